@@ -0,0 +1,30 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn digit<R: Rng + ?Sized>(rng: &mut R) -> char {
+    char::from(b'0' + rng.gen_range(1..=9))
+}
+
+/// Generates `size` lines of letters, digits and spelled out digits, each with at least one digit.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(1..=8) {
+            match rng.gen_range(0..3) {
+                0 => line.push(digit(rng)),
+                1 => line.push_str(WORDS.choose(rng).copied().unwrap_or("one")),
+                _ => line.extend((0..rng.gen_range(1..=4)).map(|_| char::from(rng.gen_range(b'a'..=b'z')))),
+            }
+        }
+        if !line.contains(|c: char| c.is_ascii_digit()) {
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, digit(rng));
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,325 @@
+pub mod generator;
+pub mod words;
+
+use std::io::BufRead;
+
+use aho_corasick::{AhoCorasick, Anchored, Input, MatchKind, StartKind};
+use common::diagnostic;
+use common::dump;
+use common::options;
+use common::solution::{Answer, Solution};
+use eyre::{eyre, Result, WrapErr};
+use words::{Compounds, Words};
+
+/// Byte ranges of the digits on a line.
+fn digit_spans(line: &str) -> Vec<(usize, usize)> {
+    line.char_indices().filter(|(_, c)| c.is_ascii_digit()).map(|(i, _)| (i, i + 1)).collect()
+}
+
+/// The first digit on a line followed by the last, found in one pass.
+fn digits_value(line: &[u8]) -> Option<u64> {
+    let mut found = None;
+    for &b in line.iter().filter(|b| b.is_ascii_digit()) {
+        let digit = u64::from(b - b'0');
+        found = Some((found.map_or(digit, |(first, _)| first), digit));
+    }
+    found.map(|(first, last)| first * 10 + last)
+}
+
+/// Finds the numbers on a line, spelled out or as digits, for part 2.
+struct Matcher {
+    /// Leftmost longest matching, which finds [`words::first_and_last`]'s first match
+    /// directly and its last with anchored searches back from the end.
+    longest: AhoCorasick,
+    /// Every match, overlapping or not, for explaining a line.
+    overlapping: AhoCorasick,
+    values: Vec<u64>,
+    longest_pattern: usize,
+    compounds: Compounds,
+}
+
+impl Matcher {
+    fn new(words: &Words, compounds: Compounds) -> Result<Self> {
+        let (patterns, values) = words.patterns();
+        let longest = AhoCorasick::builder().match_kind(MatchKind::LeftmostLongest).start_kind(StartKind::Both).build(&patterns)?;
+        let overlapping = AhoCorasick::new(&patterns)?;
+        let longest_pattern = patterns.iter().map(String::len).max().unwrap_or(0);
+        Ok(Matcher { longest, overlapping, values, longest_pattern, compounds })
+    }
+
+    /// The matcher for the table named by the `words` option and the `compounds` rule.
+    fn from_options() -> Result<Self> {
+        let words = match options::get("words") {
+            Some(spec) => Words::load(&spec)?,
+            None => Words::english(),
+        };
+        Self::new(&words, options::parse::<Compounds>("compounds")?.unwrap_or_default())
+    }
+
+    /// The calibration value of a line, without looking at more of it than needed.
+    ///
+    /// The first match is the leftmost, or the longest of those starting there. The last
+    /// ends latest, or is the longest of those ending there, so it is searched for at each
+    /// start from the end backwards until no match starting further back could end as late.
+    fn value(&self, line: &[u8]) -> Option<u64> {
+        let first = self.longest.find(line)?;
+        let mut last = first;
+        for start in (first.start()..line.len()).rev() {
+            if start + self.longest_pattern < last.end() {
+                break;
+            }
+            let found = self.longest.find(Input::new(line).span(start..line.len()).anchored(Anchored::Yes));
+            if let Some(m) = found.filter(|m| m.end() >= last.end()) {
+                last = m;
+            }
+        }
+        let value = |m: aho_corasick::Match| self.values[m.pattern().as_usize()];
+        Some(self.compounds.combine(value(first), value(last)))
+    }
+
+    fn spans(&self, line: &str) -> Vec<(usize, usize)> {
+        self.overlapping.find_overlapping_iter(line).map(|m| (m.start(), m.end())).collect()
+    }
+}
+
+/// One way of reading a line's calibration value: digits alone for part 1, or digits and
+/// spelled out numbers for part 2.
+#[derive(Clone, Copy)]
+enum Pass<'m> {
+    Digits,
+    Words(&'m Matcher),
+}
+
+impl Pass<'_> {
+    fn value(self, line: &[u8]) -> Option<u64> {
+        match self {
+            Pass::Digits => digits_value(line),
+            Pass::Words(matcher) => matcher.value(line),
+        }
+    }
+
+    /// Prints how this pass read a line; the words pass lists the digits alone as well.
+    fn explain(self, number: usize, line: &[u8], value: Option<u64>) {
+        let text = String::from_utf8_lossy(line);
+        let digits = digit_spans(&text);
+        match self {
+            Pass::Digits => explain(number, &text, &digits, &[("digits", &digits)], value, "digits"),
+            Pass::Words(matcher) => {
+                let spans = matcher.spans(&text);
+                explain(number, &text, &spans, &[("digits", &digits), ("words", &spans)], value, "digits or number words");
+            }
+        }
+    }
+}
+
+/// A running sum of calibration values, remembering the lines that had none.
+#[derive(Debug, Default)]
+struct Total {
+    sum: u64,
+    /// The number and text of the first line with no number, which the error underlines.
+    first_missing: Option<(usize, String)>,
+    second_missing: Option<usize>,
+    missing: usize,
+}
+
+impl Total {
+    fn add(&mut self, number: usize, line: &[u8], value: Option<u64>) {
+        match value {
+            Some(value) => self.sum += value,
+            None => {
+                if self.first_missing.is_none() {
+                    self.first_missing = Some((number, String::from_utf8_lossy(line).into_owned()));
+                } else if self.second_missing.is_none() {
+                    self.second_missing = Some(number);
+                }
+                self.missing += 1;
+            }
+        }
+    }
+
+    /// The sum, or an error at the first line of `name` with no number, saying which or
+    /// how many later lines have none.
+    fn finish(self, name: &str) -> Result<u64> {
+        let Some((line, text)) = self.first_missing else {
+            return Ok(self.sum);
+        };
+        let msg = match (self.missing, self.second_missing) {
+            (2, Some(other)) => format!("number not found, nor on line {other}"),
+            (1, _) => "number not found".to_string(),
+            (n, _) => format!("number not found, nor on {} later lines", n - 1),
+        };
+        Err(diagnostic::render(name, line, 1, text.chars().count(), &text, &msg))
+    }
+}
+
+/// Sums the calibration values each pass reads, in one go over `reader` with one line in
+/// memory at a time. With `--dump`, explains how the last pass read each line.
+///
+/// Lines with no number are counted rather than stopping the sum, and reported by the
+/// pass's result. The outer error is for input that cannot be read.
+fn sum<R: BufRead>(name: &str, mut reader: R, passes: &[Pass]) -> Result<Vec<Result<u64>>> {
+    let mut totals: Vec<Total> = passes.iter().map(|_| Total::default()).collect();
+    let mut buffer = Vec::new();
+    for number in 1.. {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer).wrap_err_with(|| format!("cannot read {name}"))? == 0 {
+            break;
+        }
+        let line = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        for (i, (pass, total)) in passes.iter().zip(&mut totals).enumerate() {
+            let value = pass.value(line);
+            if dump::enabled() && i + 1 == passes.len() {
+                pass.explain(number, line, value);
+            }
+            total.add(number, line, value);
+        }
+    }
+    Ok(totals.into_iter().map(|total| total.finish(name)).collect())
+}
+
+/// Solves the parts asked for in one pass over `reader`, so calibration documents of any
+/// size fit in memory. This is how `aoc run` and the day's binary read their inputs.
+pub fn stream(name: &str, reader: Box<dyn BufRead>, parts: &[u8]) -> Result<Vec<Result<Answer>>> {
+    let matcher = if parts.contains(&2) { Some(Matcher::from_options()?) } else { None };
+    let passes = parts
+        .iter()
+        .map(|&part| match (part, &matcher) {
+            (1, _) => Ok(Pass::Digits),
+            (2, Some(matcher)) => Ok(Pass::Words(matcher)),
+            _ => Err(eyre!("no part {part}")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(sum(name, reader, &passes)?.into_iter().map(|total| total.map(Answer::from)).collect())
+}
+
+/// Lists the matches of one pass over a line, marking the ones read as the first and last.
+fn describe(line: &str, spans: &[(usize, usize)]) -> String {
+    let chosen = words::first_and_last(spans);
+    let listed: Vec<_> = spans
+        .iter()
+        .enumerate()
+        .map(|(i, &(start, end))| {
+            let marks: Vec<_> = [(chosen.map(|c| c.0), "first"), (chosen.map(|c| c.1), "last")]
+                .into_iter()
+                .filter(|&(at, _)| at == Some(i))
+                .map(|(_, mark)| mark)
+                .collect();
+            match marks.is_empty() {
+                true => format!("{start}..{end} {}", &line[start..end]),
+                false => format!("{start}..{end} {} ({})", &line[start..end], marks.join(", ")),
+            }
+        })
+        .collect();
+    match listed.is_empty() {
+        true => "none".to_string(),
+        false => listed.join(", "),
+    }
+}
+
+/// Underlines the spans read as the first and last number, by character so that spelled
+/// digits outside ASCII line up.
+fn underline(line: &str, spans: &[(usize, usize)]) -> String {
+    let mut marks = vec![' '; line.chars().count()];
+    if let Some((first, last)) = words::first_and_last(spans) {
+        for (start, end) in [spans[first], spans[last]] {
+            let at = line[..start].chars().count();
+            for mark in &mut marks[at..at + line[start..end].chars().count()] {
+                *mark = '^';
+            }
+        }
+    }
+    marks.into_iter().collect::<String>().trim_end().to_string()
+}
+
+/// Prints how a line was read when dumps are enabled: the line with the first and last
+/// number underlined, then every match of each pass with byte offsets, then its value or
+/// what the line lacks.
+fn explain(
+    number: usize,
+    line: &str,
+    chosen: &[(usize, usize)],
+    passes: &[(&str, &[(usize, usize)])],
+    value: Option<u64>,
+    lacking: &str,
+) {
+    let mut text = format!("{line}\n");
+    if !chosen.is_empty() {
+        text.push_str(&format!("{}\n", underline(line, chosen)));
+    }
+    for (name, spans) in passes {
+        text.push_str(&format!("{name:<7} {}\n", describe(line, spans)));
+    }
+    match value {
+        Some(value) => text.push_str(&format!("value   {value}\n")),
+        None => text.push_str(&format!("value   none, the line has no {lacking}\n")),
+    }
+    dump::section(&format!("line {number}"), text);
+}
+
+pub struct Trebuchet<'a> {
+    name: &'a str,
+    body: &'a str,
+}
+
+impl<'a> Solution<'a> for Trebuchet<'a> {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Trebuchet { name: fname, body })
+    }
+
+    /// With `--dump`, explains how each line was read.
+    fn part1(&self) -> Result<u64> {
+        sum(self.name, self.body.as_bytes(), &[Pass::Digits])?.remove(0)
+    }
+
+    /// Digits may also be spelled out, in English or in the table the `words` option names.
+    /// With `words=extended`, the `compounds` option says how numbers over nine are read.
+    /// With `--dump`, explains how each line was read by both digits alone and words.
+    fn part2(&self) -> Result<u64> {
+        let matcher = Matcher::from_options()?;
+        sum(self.name, self.body.as_bytes(), &[Pass::Words(&matcher)])?.remove(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// The value as read from every overlapping match, which `Matcher::value` avoids finding.
+    fn overlapping_value(matcher: &Matcher, line: &str) -> Option<u64> {
+        let matches: Vec<_> = matcher.overlapping.find_overlapping_iter(line).collect();
+        let spans: Vec<_> = matches.iter().map(|m| (m.start(), m.end())).collect();
+        let (first, last) = words::first_and_last(&spans)?;
+        let value = |i: usize| matcher.values[matches[i].pattern().as_usize()];
+        Some(matcher.compounds.combine(value(first), value(last)))
+    }
+
+    proptest! {
+        #[test]
+        fn searching_from_both_ends_matches_every_overlap(seed in any::<u64>(), size in 1usize..20) {
+            let body = generator::input(&mut StdRng::seed_from_u64(seed), size);
+            let matcher = Matcher::new(&Words::english(), Compounds::Digits).unwrap();
+            for line in body.lines() {
+                prop_assert_eq!(matcher.value(line.as_bytes()), overlapping_value(&matcher, line), "{}", line);
+            }
+        }
+
+        #[test]
+        fn extended_compounds_match_every_overlap(
+            tokens in prop::collection::vec(prop::sample::select(vec!["x", "-", "7", "zero", "eight", "two", "twenty", "twenty-one", "eighteen", "sixty-six", "seventy", "nine"]), 0..10),
+            value in any::<bool>(),
+        ) {
+            let line = tokens.concat();
+            let compounds = if value { Compounds::Value } else { Compounds::Digits };
+            let matcher = Matcher::new(&Words::extended(), compounds).unwrap();
+            prop_assert_eq!(matcher.value(line.as_bytes()), overlapping_value(&matcher, &line), "{}", line);
+        }
+    }
+}
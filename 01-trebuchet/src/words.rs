@@ -0,0 +1,197 @@
+//! The spelled out digits part 2 reads, in English unless the `words` option picks another
+//! table: a built in language, `extended` English numbers up to ninety-nine, or a file of
+//! `word digit` lines.
+
+use std::fs;
+use std::str::FromStr;
+
+use common::diagnostic::Source;
+use eyre::{eyre, Report, Result, WrapErr};
+
+/// Built in tables, spelling the digits one to nine.
+const PRESETS: [(&str, [&str; 9]); 4] = [
+    ("en", ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"]),
+    ("de", ["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"]),
+    ("fr", ["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"]),
+    ("es", ["uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve"]),
+];
+
+const TEENS: [&str; 10] = ["ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"];
+const TENS: [&str; 8] = ["twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+/// A table of words and the numbers they spell, which are single digits except in the
+/// `extended` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Words {
+    entries: Vec<(String, u64)>,
+}
+
+impl Words {
+    pub fn english() -> Self {
+        Self::spelling(&PRESETS[0].1)
+    }
+
+    /// The built in table for a language code such as `de`.
+    pub fn preset(name: &str) -> Option<Self> {
+        PRESETS.iter().find(|(code, _)| *code == name).map(|(_, words)| Self::spelling(words))
+    }
+
+    /// English numbers from zero to ninety-nine, with compounds such as `twenty-one` hyphenated.
+    ///
+    /// Shorter numbers inside longer ones, like `six` in `sixty-one`, still match; see
+    /// [`first_and_last`] for which is read.
+    pub fn extended() -> Self {
+        let mut entries = vec![("zero".to_string(), 0)];
+        entries.extend(Self::spelling(&PRESETS[0].1).entries);
+        entries.extend(TEENS.iter().zip(10..).map(|(word, n)| (word.to_string(), n)));
+        for (tens, n) in TENS.iter().zip((20..).step_by(10)) {
+            entries.push((tens.to_string(), n));
+            entries.extend(PRESETS[0].1.iter().zip(1..).map(|(unit, u)| (format!("{tens}-{unit}"), n + u)));
+        }
+        Words { entries }
+    }
+
+    fn spelling(words: &[&str; 9]) -> Self {
+        Words { entries: words.iter().zip(1..).map(|(word, digit)| (word.to_string(), digit)).collect() }
+    }
+
+    /// The table named by the `words` option: a language code, `extended`, or else a file to load.
+    pub fn load(spec: &str) -> Result<Self> {
+        if spec == "extended" {
+            return Ok(Self::extended());
+        }
+        if let Some(words) = Self::preset(spec) {
+            return Ok(words);
+        }
+        let codes: Vec<_> = PRESETS.iter().map(|(code, _)| *code).chain(["extended"]).collect();
+        let body = fs::read_to_string(spec)
+            .wrap_err_with(|| format!("words={spec} is neither a language ({}) nor a readable file", codes.join(", ")))?;
+        Self::parse(spec, &body)
+    }
+
+    /// Parses a table with one `word digit` pair per line. Blank lines and lines starting
+    /// with `#` are skipped.
+    pub fn parse(fname: &str, body: &str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let mut spans = Vec::new();
+        for line in body.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (Some(word), Some(digit), None) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(source.error(line, "expected a word and the digit it spells"));
+            };
+            match source.parse::<u8>(digit)? {
+                d @ 0..=9 => spans.push((word, d)),
+                d => return Err(source.error(digit, format!("{d} is not a single digit"))),
+            }
+        }
+        if spans.is_empty() {
+            return Err(source.eof("the table has no words"));
+        }
+        check(&spans).map_err(|(i, msg)| source.error(spans[i].0, msg))?;
+        Ok(Words { entries: spans.iter().map(|&(word, digit)| (word.to_string(), u64::from(digit))).collect() })
+    }
+
+    /// The words followed by the digits `1` to `9`, and the value of each, in pattern order.
+    pub fn patterns(&self) -> (Vec<String>, Vec<u64>) {
+        let digits = (1..=9).map(|d| (d.to_string(), d));
+        self.entries.iter().cloned().chain(digits).unzip()
+    }
+}
+
+/// How the first and last number found on a line make its calibration value, which only
+/// differs once numbers can have more than one digit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compounds {
+    /// The first digit of the first number and the last digit of the last, so `twenty-one`
+    /// alone reads as 21 and `sixty` as 60.
+    #[default]
+    Digits,
+    /// Both numbers written out in full one after the other, so `twenty-one` alone reads
+    /// as 2121 and `sixty` and `three` as 603.
+    Value,
+}
+
+impl Compounds {
+    pub fn combine(self, first: u64, last: u64) -> u64 {
+        match self {
+            Compounds::Digits => {
+                let leading = std::iter::successors(Some(first), |n| Some(n / 10)).find(|&n| n < 10).unwrap_or(0);
+                leading * 10 + last % 10
+            }
+            Compounds::Value => {
+                let width = std::iter::successors(Some(last), |n| Some(n / 10)).take_while(|&n| n >= 10).count() as u32 + 1;
+                first * 10u64.pow(width) + last
+            }
+        }
+    }
+}
+
+impl FromStr for Compounds {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "digits" => Ok(Compounds::Digits),
+            "value" => Ok(Compounds::Value),
+            _ => Err(eyre!("expected digits or value")),
+        }
+    }
+}
+
+/// The match read as the first number and the one read as the last, given as
+/// `(start, end)` byte ranges in the order the matcher found them.
+///
+/// Matches may overlap, as in `eightwo`, and the first is the one starting earliest and the
+/// last the one ending latest. When several start or end at the same place the longest
+/// wins, so `twenty-one` is read whole rather than as `twenty` or `one`.
+pub fn first_and_last(matches: &[(usize, usize)]) -> Option<(usize, usize)> {
+    let first = (0..matches.len()).min_by_key(|&i| (matches[i].0, std::cmp::Reverse(matches[i].1)))?;
+    let last = (0..matches.len()).max_by_key(|&i| (matches[i].1, std::cmp::Reverse(matches[i].0)))?;
+    Some((first, last))
+}
+
+/// Rejects tables whose overlapping matches could be read more than one way, giving the
+/// index of the offending entry.
+///
+/// A word inside another word with a different digit is read one way or the other only
+/// because [`first_and_last`] prefers the longer match, so such tables are turned away
+/// rather than trusted to mean that. Words also cannot repeat or contain digits, which are
+/// matched on their own. The `extended` table is built in and relies on the longer match.
+fn check(entries: &[(&str, u8)]) -> Result<(), (usize, String)> {
+    for (i, &(word, digit)) in entries.iter().enumerate() {
+        if word.contains(|c: char| c.is_ascii_digit()) {
+            return Err((i, format!("'{word}' contains a digit, which is matched on its own")));
+        }
+        if entries[..i].iter().any(|&(other, _)| other == word) {
+            return Err((i, format!("'{word}' is listed twice")));
+        }
+        for &(other, d) in &entries[..i] {
+            let (inner, outer) = match (word.contains(other), other.contains(word)) {
+                (true, _) => ((other, d), (word, digit)),
+                (_, true) => ((word, digit), (other, d)),
+                _ => continue,
+            };
+            if d != digit {
+                return Err((i, format!("'{}' ({}) is inside '{}' ({}), so which comes first is ambiguous", inner.0, inner.1, outer.0, outer.1)));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_unambiguous() {
+        for (code, words) in PRESETS {
+            let entries: Vec<_> = words.into_iter().zip(1..).collect();
+            assert_eq!(check(&entries), Ok(()), "{code}");
+        }
+    }
+}
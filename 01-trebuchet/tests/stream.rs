@@ -0,0 +1,71 @@
+//! Reading calibration documents a line at a time.
+
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use common::solution::{Answer, Solution};
+use eyre::Result;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use trebuchet::Trebuchet;
+
+fn stream(name: &str, body: &str, parts: &[u8]) -> Result<Vec<Result<Answer>>> {
+    trebuchet::stream(name, Box::new(Cursor::new(body.to_string())), parts)
+}
+
+#[test]
+fn streaming_agrees_with_reading_into_memory() -> Result<()> {
+    for seed in 0..20 {
+        let body = trebuchet::generator::input(&mut StdRng::seed_from_u64(seed), 200);
+        let solution = Trebuchet::parse("generated", &body)?;
+        let answers = stream("generated", &body, &[1, 2])?.into_iter().collect::<Result<Vec<_>>>()?;
+        assert_eq!(answers, [solution.part1()?.into(), solution.part2()?.into()]);
+    }
+    Ok(())
+}
+
+#[test]
+fn binary_streams_standard_input() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_trebuchet"))
+        .env_remove("AOC_OPTS")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"two1nine\r\n7pqrstsixteen\r\nx4").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n{}\n", 11 + 77 + 44, 29 + 76 + 44));
+}
+
+#[test]
+fn lines_without_digits_are_counted() {
+    let answers = stream("doc.txt", "1\nabc\n2\nxyz\nq\n", &[1]).unwrap();
+    assert_eq!(
+        answers[0].as_ref().unwrap_err().to_string(),
+        "doc.txt:2:1: number not found, nor on 2 later lines\n  |\n2 | abc\n  | ^^^"
+    );
+
+    let answers = stream("doc.txt", "1\nabc\nxyz\n", &[1, 2]).unwrap();
+    for answer in answers {
+        assert!(answer.unwrap_err().to_string().starts_with("doc.txt:2:1: number not found, nor on line 3\n"));
+    }
+}
+
+#[test]
+fn binary_goes_on_past_a_failing_input() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let (bad, good) = (dir.join("trebuchet-bad.txt"), dir.join("trebuchet-good.txt"));
+    fs::write(&bad, "nothing\n").unwrap();
+    fs::write(&good, "1abc2\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_trebuchet")).env_remove("AOC_OPTS").args([&bad, &good]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("trebuchet-bad.txt:1:1: number not found"), "{stderr}");
+    assert!(stderr.contains("1 of 2 inputs failed"), "{stderr}");
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("trebuchet-good.txt <==\n12\n12\n"));
+}
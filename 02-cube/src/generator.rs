@@ -0,0 +1,19 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates `size` games of up to six draws, each showing some of the three colors.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size.max(1) {
+        let draws: Vec<_> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let shown = rng.gen_range(1..=3);
+                colors[..shown].iter().map(|c| format!("{} {c}", rng.gen_range(1..=20))).collect::<Vec<_>>().join(", ")
+            })
+            .collect();
+        out.push_str(&format!("Game {id}: {}\n", draws.join("; ")));
+    }
+    out
+}
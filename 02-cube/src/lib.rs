@@ -0,0 +1,132 @@
+pub mod generator;
+
+use common::diagnostic::Source;
+use common::solution::Solution;
+use eyre::eyre;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Rolls {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+impl Rolls {
+    fn new(red: i32, green: i32, blue: i32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+        }
+    }
+
+    fn keep_max_color(&mut self, rhs: &Self) {
+        self.red = std::cmp::max(self.red, rhs.red);
+        self.green = std::cmp::max(self.green, rhs.green);
+        self.blue = std::cmp::max(self.blue, rhs.blue);
+    }
+
+    fn power(&self) -> i32 {
+        self.red * self.green * self.blue
+    }
+
+    fn valid(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RollsBuilder {
+    red: Option<i32>,
+    green: Option<i32>,
+    blue: Option<i32>,
+}
+
+impl RollsBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn add_color(&mut self, color: &str, num: i32) -> eyre::Result<()> {
+        match color {
+            "red" => {
+                self.red = Some(num);
+                Ok(())
+            }
+            "green" => {
+                self.green = Some(num);
+                Ok(())
+            }
+            "blue" => {
+                self.blue = Some(num);
+                Ok(())
+            }
+            _ => Err(eyre!("unknown color {}", color)),
+        }
+    }
+
+    fn build(self) -> Rolls {
+        Rolls::new(self.red.unwrap_or(0), self.green.unwrap_or(0), self.blue.unwrap_or(0))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Game {
+    id: i32,
+    valid: bool,
+    max_rolls: Rolls,
+}
+
+fn parse_games(source: Source) -> eyre::Result<Vec<Game>> {
+    let mut games = Vec::new();
+    for line in source.body().lines() {
+        let mut max_rolls = Rolls::default();
+        let mut valid = true;
+        let (id_str, shows) =
+            line.split_once(':').ok_or_else(|| source.error(line, "invalid line"))?;
+        let id_str = id_str.strip_prefix("Game ").ok_or_else(|| source.error(id_str, "expected 'Game <id>'"))?;
+        let id: i32 = source.parse(id_str)?;
+        for show in shows.split(';') {
+            let mut builder = RollsBuilder::new();
+            for dice in show.split(',') {
+                let dice = dice.trim();
+                let (num_str, color) =
+                    dice.split_once(' ').ok_or_else(|| source.error(dice, "expected '<count> <color>'"))?;
+                let num: i32 = source.parse(num_str)?;
+                builder.add_color(color, num).map_err(|e| source.error(color, e))?;
+            }
+            let rolls = builder.build();
+            max_rolls.keep_max_color(&rolls);
+            valid = valid && rolls.valid();
+        }
+        games.push(Game {
+            id,
+            valid,
+            max_rolls,
+        });
+    }
+    Ok(games)
+}
+
+pub struct Cube {
+    games: Vec<Game>,
+}
+
+impl<'a> Solution<'a> for Cube {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> eyre::Result<Self> {
+        Ok(Cube {
+            games: parse_games(Source::new(fname, body))?,
+        })
+    }
+
+    fn part1(&self) -> eyre::Result<u64> {
+        Ok(u64::try_from(self.games.iter().filter(|g| g.valid).map(|g| g.id).sum::<i32>())?)
+    }
+
+    fn part2(&self) -> eyre::Result<u64> {
+        Ok(u64::try_from(self.games.iter().map(|g| g.max_rolls.power()).sum::<i32>())?)
+    }
+}
@@ -0,0 +1,32 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+/// Generates a `size` by `size` schematic of part numbers below 1000 scattered among symbols.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let room = size - line.len();
+            if rng.gen_bool(0.15) {
+                let digits = rng.gen_range(1..=3).min(room);
+                let number = rng.gen_range(10usize.pow(digits as u32 - 1)..10usize.pow(digits as u32));
+                line.push_str(&number.to_string());
+                if line.len() < size {
+                    line.push('.');
+                }
+            } else if rng.gen_bool(0.1) {
+                let symbol = if rng.gen_bool(0.5) { '*' } else { *SYMBOLS.choose(rng).unwrap_or(&'#') };
+                line.push(symbol);
+            } else {
+                line.push('.');
+            }
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
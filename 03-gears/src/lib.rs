@@ -0,0 +1,137 @@
+pub mod generator;
+
+use common::grid;
+use common::solution::Solution;
+use eyre::Result;
+use ndarray::{s, Array2, ArrayBase, Data, Ix2};
+
+fn part_sums<D: Data<Elem = char>>(schematic: &ArrayBase<D, Ix2>) -> Result<(u32, u32)> {
+    let (rows, columns) = schematic.dim();
+    let mut sum = 0u32;
+    let mut gear_sum = 0u32;
+    for ((row, col), c) in schematic.indexed_iter() {
+        if !c.is_ascii_digit() && *c != '.' {
+            let mut nums = Vec::new();
+            let left_count = schematic
+                .slice(s!(row,..col;-1))
+                .into_iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            let left_num: String =
+                schematic.slice(s!(row, (col - left_count)..col)).into_iter().collect();
+            if !left_num.is_empty() {
+                nums.push(left_num.parse::<u32>()?);
+            }
+            if col < columns - 1 {
+                let right_num: String = schematic
+                    .slice(s!(row, (col + 1)..))
+                    .into_iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+                if !right_num.is_empty() {
+                    nums.push(right_num.parse::<u32>()?);
+                }
+            }
+            if row > 0 {
+                // top
+                let mut topleft_num = if col > 0 {
+                    let count = schematic
+                        .slice(s!(row -1,..col;-1))
+                        .into_iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .count();
+                    schematic.slice(s!(row - 1, (col - count)..col)).into_iter().collect()
+                } else {
+                    String::new()
+                };
+                let top_num: String = schematic
+                    .slice(s!(row - 1, col..col + 1))
+                    .into_iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+                let topright_num = if col < columns - 1 {
+                    schematic
+                        .slice(s!(row - 1, (col + 1)..))
+                        .into_iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .collect()
+                } else {
+                    String::new()
+                };
+                if !top_num.is_empty() {
+                    topleft_num.push_str(top_num.as_str());
+                    topleft_num.push_str(topright_num.as_str());
+                } else if !topright_num.is_empty() {
+                        nums.push(topright_num.parse::<u32>()?);
+                }
+                if !topleft_num.is_empty() {
+                    nums.push(topleft_num.parse::<u32>()?);
+                }
+            }
+            if row < rows - 1 {
+                // bottom
+                let mut topleft_num = if col > 0 {
+                    let count = schematic
+                        .slice(s!(row + 1,..col;-1))
+                        .into_iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .count();
+                    schematic.slice(s!(row + 1, (col - count)..col)).into_iter().collect()
+                } else {
+                    String::new()
+                };
+                let top_num: String = schematic
+                    .slice(s!(row + 1, col..col + 1))
+                    .into_iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+                let topright_num = if col < columns - 1 {
+                    schematic
+                        .slice(s!(row + 1, (col + 1)..))
+                        .into_iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .collect()
+                } else {
+                    String::new()
+                };
+                if !top_num.is_empty() {
+                    topleft_num.push_str(top_num.as_str());
+                    topleft_num.push_str(topright_num.as_str());
+                } else if !topright_num.is_empty() {
+                        nums.push(topright_num.parse::<u32>()?);
+                }
+                if !topleft_num.is_empty() {
+                    nums.push(topleft_num.parse::<u32>()?);
+                }
+            }
+            sum += nums.iter().sum::<u32>();
+            if *c == '*' && nums.len() == 2 {
+                gear_sum += nums.iter().product::<u32>();
+            }
+        }
+    }
+    Ok((sum, gear_sum))
+}
+
+pub struct Gears {
+    schematic: Array2<char>,
+}
+
+impl<'a> Solution<'a> for Gears {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Gears {
+            schematic: grid::parse(fname, body)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(part_sums(&self.schematic)?.0.into())
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(part_sums(&self.schematic)?.1.into())
+    }
+}
@@ -0,0 +1,32 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Copies of a single card are kept below this so part two cannot overflow.
+const MAX_COPIES: u64 = 1 << 40;
+
+/// Generates `size` cards of ten winning numbers and twenty-five numbers you have.
+///
+/// No card wins copies of cards past the end of the table.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut copies = vec![1u64; size];
+    let mut out = String::new();
+    for card in 0..size {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(rng);
+        let (winning, rest) = numbers.split_at(10);
+        let mut wins = rng.gen_range(0..=10).min(size - card - 1);
+        let current = copies[card];
+        if rng.gen_bool(0.5) || copies[card + 1..=card + wins].iter().any(|&c| c + current > MAX_COPIES) {
+            wins = 0;
+        }
+        for c in &mut copies[card + 1..=card + wins] {
+            *c += current;
+        }
+        let mut have: Vec<u32> = winning[..wins].iter().chain(&rest[..25 - wins]).copied().collect();
+        have.shuffle(rng);
+        let show = |ns: &[u32]| ns.iter().map(|n| format!("{n:2}")).collect::<Vec<_>>().join(" ");
+        out.push_str(&format!("Card {:3}: {} | {}\n", card + 1, show(winning), show(&have)));
+    }
+    out
+}
@@ -0,0 +1,67 @@
+pub mod generator;
+
+use common::diagnostic::Source;
+use common::solution::Solution;
+use eyre::eyre;
+use std::collections::HashSet;
+
+fn card_wins(source: Source) -> eyre::Result<Vec<u32>> {
+    let mut card_wins = Vec::new();
+    for line in source.body().lines() {
+        let (_, num_str) = line.split_once(':').ok_or_else(|| source.error(line, "invalid line"))?;
+        let (winner_str, scratch_str) =
+            num_str.split_once('|').ok_or_else(|| source.error(num_str, "missing '|'"))?;
+        let mut winners = HashSet::<u32>::new();
+        for token in winner_str.split_whitespace() {
+            winners.insert(source.parse(token)?);
+        }
+        let wins = scratch_str.split_whitespace().map(|t| {
+            let n: u32 = source.parse(t)?;
+            if winners.contains(&n) {
+                Ok(1)
+            } else {
+                Ok(0)
+            }
+        }).sum::<eyre::Result<u32>>()?;
+        card_wins.push(wins);
+    }
+    Ok(card_wins)
+}
+
+pub struct Scratchcards {
+    card_wins: Vec<u32>,
+}
+
+impl<'a> Solution<'a> for Scratchcards {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> eyre::Result<Self> {
+        Ok(Scratchcards {
+            card_wins: card_wins(Source::new(fname, body))?,
+        })
+    }
+
+    fn part1(&self) -> eyre::Result<u64> {
+        let mut sum = 0u64;
+        for &wins in self.card_wins.iter() {
+            if wins > 0 {
+                sum += 1 << (wins - 1);
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part2(&self) -> eyre::Result<u64> {
+        let mut card_count: Vec<_> = std::iter::repeat_n(1u64, self.card_wins.len()).collect();
+        for (i, &wins) in self.card_wins.iter().enumerate() {
+            let current = card_count[i];
+            for x in 0..wins {
+                *card_count
+                    .get_mut(i + x as usize + 1)
+                    .ok_or_else(|| eyre!("card {} wins copies past the end of the table", i + 1))? += current;
+            }
+        }
+        Ok(card_count.into_iter().sum::<u64>())
+    }
+}
@@ -0,0 +1,38 @@
+use rand::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Values stay below this, like in the puzzle.
+const LIMIT: u64 = 1 << 32;
+
+/// Generates an almanac with `size` seed ranges and `size` entries in every map.
+///
+/// Seed ranges are short so part two stays quick to brute force, and the source ranges
+/// of each map never overlap.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let seeds: Vec<_> = (0..size)
+        .map(|_| format!("{} {}", rng.gen_range(0..LIMIT - 1000), rng.gen_range(1..=1000)))
+        .collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        out.push_str(&format!("\n{name} map:\n"));
+        let mut cuts: Vec<u64> = (0..2 * size).map(|_| rng.gen_range(0..LIMIT)).collect();
+        cuts.sort();
+        cuts.dedup();
+        for pair in cuts.chunks_exact(2) {
+            let length = pair[1] - pair[0];
+            let dest = rng.gen_range(0..LIMIT - length);
+            out.push_str(&format!("{dest} {} {length}\n", pair[0]));
+        }
+    }
+    out
+}
@@ -0,0 +1,215 @@
+pub mod generator;
+
+use std::str::FromStr;
+
+use common::diagnostic::Source;
+use common::solution::Solution;
+use eyre::{eyre, Result, WrapErr};
+use rayon::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Map {
+    source: u64,
+    dest: u64,
+    length: u64,
+}
+impl Map {
+    fn source_end(&self) -> u64 { self.source + self.length }
+
+    fn source_contains(&self, index: u64) -> bool {
+        index >= self.source && index < self.source_end()
+    }
+}
+
+impl FromStr for Map {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = s
+            .split_whitespace()
+            .map(|t| t.parse().wrap_err("parsing integer"))
+            .collect::<Result<Vec<u64>>>()?;
+        if tokens.len() == 3 {
+            if tokens[0].checked_add(tokens[2]).zip(tokens[1].checked_add(tokens[2])).is_none() {
+                return Err(eyre!("range overflows"));
+            }
+            Ok(Map {
+                source: tokens[1],
+                dest: tokens[0],
+                length: tokens[2],
+            })
+        } else {
+            Err(eyre!("expected 3 numbers on line got {}", tokens.len()))
+        }
+    }
+}
+
+fn lookup(table: &[Map], source: u64) -> u64 {
+    match table.binary_search_by_key(&source, |m| m.source) {
+        Ok(index) => table[index].dest,
+        Err(index) => {
+            if index > 0 {
+                let map = table[index-1];
+                if map.source_contains(source) {
+                    map.dest + (source - map.source)
+                } else {
+                    source
+                }
+            } else {
+                source
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Soilmap {
+    seeds: Vec<u64>,
+    seed_soil: Vec<Map>,
+    soil_fertilizer: Vec<Map>,
+    fertilizer_water: Vec<Map>,
+    water_light: Vec<Map>,
+    light_temp: Vec<Map>,
+    temp_humidity: Vec<Map>,
+    humidity_location: Vec<Map>,
+}
+
+impl Soilmap {
+    fn location(&self, seed: u64) -> u64 {
+        let soil = lookup(&self.seed_soil, seed);
+        let fert = lookup(&self.soil_fertilizer, soil);
+        let water = lookup(&self.fertilizer_water, fert);
+        let light = lookup(&self.water_light, water);
+        let temp = lookup(&self.light_temp, light);
+        let humidity = lookup(&self.temp_humidity, temp);
+        lookup(&self.humidity_location, humidity)
+    }
+}
+
+fn parse_almanac(source: Source) -> Result<Soilmap> {
+    let mut lines = source.body().lines();
+    let seed_line = lines.next().ok_or_else(|| source.eof("missing seeds"))?;
+    let seed_tokens =
+        seed_line.strip_prefix("seeds:").ok_or_else(|| source.error(seed_line, "expected 'seeds:'"))?;
+    let seeds = seed_tokens.split_whitespace().map(|t| source.parse(t)).collect::<Result<Vec<u64>>>()?;
+    lines.next().ok_or_else(|| source.eof("missing maps"))?;
+    let mut read_map = |name: &str| -> Result<Vec<Map>> {
+        let header = lines.next().ok_or_else(|| source.eof(format!("missing {name} map")))?;
+        if header != format!("{name} map:") {
+            return Err(source.error(header, format!("expected '{name} map:'")));
+        }
+        let mut result = lines
+            .by_ref()
+            .take_while(|l| !l.is_empty())
+            .map(|l| source.parse::<Map>(l))
+            .collect::<Result<Vec<_>>>()?;
+        result.sort();
+        Ok(result)
+    };
+    Ok(Soilmap {
+        seeds,
+        seed_soil: read_map("seed-to-soil")?,
+        soil_fertilizer: read_map("soil-to-fertilizer")?,
+        fertilizer_water: read_map("fertilizer-to-water")?,
+        water_light: read_map("water-to-light")?,
+        light_temp: read_map("light-to-temperature")?,
+        temp_humidity: read_map("temperature-to-humidity")?,
+        humidity_location: read_map("humidity-to-location")?,
+    })
+}
+
+impl<'a> Solution<'a> for Soilmap {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        parse_almanac(Source::new(fname, body))
+    }
+
+    fn part1(&self) -> Result<u64> {
+        self.seeds.iter().map(|s| self.location(*s)).min().ok_or(eyre!("minimum not found"))
+    }
+
+    fn part2(&self) -> Result<u64> {
+        self.seeds
+            .chunks_exact(2)
+            .flat_map(|c| c[0]..c[0].saturating_add(c[1]))
+            .par_bridge()
+            .map(|s| self.location(s))
+            .min()
+            .ok_or(eyre!("minimum location 2 not found"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Maps whole ranges through a sorted table by splitting them at the map boundaries.
+    fn map_ranges(table: &[Map], ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+        for range in ranges {
+            let mut current = range.start;
+            for map in table.iter().filter(|m| m.source < range.end && m.source_end() > range.start) {
+                if current < map.source {
+                    mapped.push(current..map.source);
+                    current = map.source;
+                }
+                let end = range.end.min(map.source_end());
+                mapped.push(map.dest + (current - map.source)..map.dest + (end - map.source));
+                current = end;
+            }
+            if current < range.end {
+                mapped.push(current..range.end);
+            }
+        }
+        mapped
+    }
+
+    fn tables(almanac: &Soilmap) -> [&[Map]; 7] {
+        [
+            &almanac.seed_soil,
+            &almanac.soil_fertilizer,
+            &almanac.fertilizer_water,
+            &almanac.water_light,
+            &almanac.light_temp,
+            &almanac.temp_humidity,
+            &almanac.humidity_location,
+        ]
+    }
+
+    fn almanac(seed: u64, size: usize) -> Soilmap {
+        let body = generator::input(&mut StdRng::seed_from_u64(seed), size);
+        Soilmap::parse("generated", &body).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn lookup_matches_intervals(seed in any::<u64>(), size in 1usize..6, value in 0u64..1 << 32) {
+            let almanac = almanac(seed, size);
+            for table in tables(&almanac) {
+                let mapped = map_ranges(table, std::iter::once(value..value + 1).collect());
+                let expected = lookup(table, value);
+                prop_assert_eq!(mapped.len(), 1);
+                prop_assert_eq!(mapped[0].clone(), expected..expected + 1);
+            }
+        }
+
+        #[test]
+        fn brute_force_minimum_matches_intervals(seed in any::<u64>(), size in 1usize..4) {
+            let almanac = almanac(seed, size);
+            let mut ranges: Vec<_> = almanac.seeds.chunks_exact(2).map(|c| c[0]..c[0] + c[1]).collect();
+            for table in tables(&almanac) {
+                ranges = map_ranges(table, ranges);
+            }
+            let minimum = ranges.iter().map(|r| r.start).min().unwrap();
+            prop_assert_eq!(almanac.part2().unwrap(), minimum);
+        }
+    }
+}
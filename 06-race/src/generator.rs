@@ -0,0 +1,18 @@
+use rand::Rng;
+
+/// Generates up to four races, one more for every four steps of `size`.
+///
+/// Times have at most two digits, as the kerned race in part two is brute forced.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let races = (1 + size / 4).min(4);
+    let mut times = String::from("Time:    ");
+    let mut distances = String::from("Distance:");
+    for _ in 0..races {
+        let time: u64 = rng.gen_range(7..100);
+        let hold = rng.gen_range(1..time);
+        let record = hold * (time - hold) - 1;
+        times.push_str(&format!(" {time:>4}"));
+        distances.push_str(&format!(" {record:>4}"));
+    }
+    format!("{times}\n{distances}\n")
+}
@@ -0,0 +1,72 @@
+pub mod generator;
+
+use common::diagnostic::Source;
+use common::solution::Solution;
+use eyre::Result;
+
+pub struct Race {
+    times: Vec<u64>,
+    distance: Vec<u64>,
+    single_time: u64,
+    single_distance: u64,
+}
+
+impl<'a> Solution<'a> for Race {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let mut lines = body.lines();
+        let mut field = |name: &str| {
+            let line = lines.next().ok_or_else(|| source.eof(format!("missing {name}")))?;
+            line.strip_prefix(name)
+                .and_then(|l| l.strip_prefix(':'))
+                .ok_or_else(|| source.error(line, format!("expected '{name}:'")))
+        };
+        let times_str = field("Time")?;
+        let distance_str = field("Distance")?;
+        let numbers = |s: &str| s.split_whitespace().map(|n| source.parse::<u64>(n)).collect::<Result<Vec<_>>>();
+        let times = numbers(times_str)?;
+        let distance = numbers(distance_str)?;
+        if times.len() != distance.len() {
+            return Err(source.error(distance_str, format!("expected {} distances", times.len())));
+        }
+        let single = |s: &str| s.split_whitespace().collect::<String>().parse::<u64>().map_err(|e| source.error(s, e));
+        let single_time = single(times_str)?;
+        let single_distance = single(distance_str)?;
+        Ok(Race {
+            times,
+            distance,
+            single_time,
+            single_distance,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let product: usize = self
+            .times
+            .iter()
+            .zip(self.distance.iter())
+            .map(|(time, record)| {
+                (0..*time)
+                    .filter(|v| {
+                        let travel_time = time - v;
+                        v.checked_mul(travel_time).is_none_or(|distance| distance > *record)
+                    })
+                    .count()
+            })
+            .product();
+        Ok(product as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let single_count = (0..self.single_time)
+            .filter(|v| {
+                let travel_time = self.single_time - v;
+                v.checked_mul(travel_time).is_none_or(|distance| distance > self.single_distance)
+            })
+            .count();
+        Ok(single_count as u64)
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// Generates `size` distinct hands with bids up to 1000.
+///
+/// Hands are drawn from a few card values at a time so every kind of hand turns up.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.clamp(1, 13usize.pow(5));
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    while seen.len() < size {
+        let distinct = rng.gen_range(1..=5);
+        let values: Vec<char> = CARDS.choose_multiple(rng, distinct).copied().collect();
+        let hand: String = (0..5).map(|_| *values.choose(rng).unwrap_or(&'A')).collect();
+        if seen.insert(hand.clone()) {
+            out.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+    }
+    out
+}
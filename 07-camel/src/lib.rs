@@ -0,0 +1,205 @@
+pub mod generator;
+
+use std::{cmp::Reverse, collections::BTreeMap};
+use std::collections::HashMap;
+use std::str::FromStr;
+use common::diagnostic::Source;
+use common::solution::Solution;
+use eyre::{eyre, Report, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace
+}
+
+impl FromStr for Card {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(Card::Two),
+            "3" => Ok(Card::Three),
+            "4" => Ok(Card::Four),
+            "5" => Ok(Card::Five),
+            "6" => Ok(Card::Six),
+            "7" => Ok(Card::Seven),
+            "8" => Ok(Card::Eight),
+            "9" => Ok(Card::Nine),
+            "T" => Ok(Card::Ten),
+            "J" => Ok(Card::Jack),
+            "Q" => Ok(Card::Queen),
+            "K" => Ok(Card::King),
+            "A" => Ok(Card::Ace),
+            _ => Err(eyre!("unknown card '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Classifies a hand from its per-card counts, letting jokers join the largest group.
+fn hand_kind(mut counts: Vec<u32>, jokers: u32) -> HandKind {
+    counts.sort_by_key(|c| Reverse(*c));
+    let first = counts.first().copied().unwrap_or(0) + jokers;
+    let second = counts.get(1).copied().unwrap_or(0);
+    match (first, second) {
+        (5, _) => HandKind::FiveOfAKind,
+        (4, _) => HandKind::FourOfAKind,
+        (3, 2) => HandKind::FullHouse,
+        (3, _) => HandKind::ThreeOfAKind,
+        (2, 2) => HandKind::TwoPair,
+        (2, _) => HandKind::OnePair,
+        _ => HandKind::HighCard,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand<T> {
+    kind: HandKind,
+    cards: Vec<T>
+}
+
+impl FromStr for Hand<Card> {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 5 {
+            return Err(eyre!("hand length {} must be 5", s.len()));
+        }
+        let cards = s.chars().map(|c| c.to_string().parse::<Card>()).collect::<Result<Vec<_>>>()?;
+        let mut counts = HashMap::new();
+        for card in cards.iter() {
+            *counts.entry(*card).or_insert(0) += 1;
+        }
+        let kind = hand_kind(counts.into_values().collect(), 0);
+        Ok(Hand { kind, cards} )
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum JokerCard {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Queen,
+    King,
+    Ace
+}
+
+impl FromStr for JokerCard {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(JokerCard::Two),
+            "3" => Ok(JokerCard::Three),
+            "4" => Ok(JokerCard::Four),
+            "5" => Ok(JokerCard::Five),
+            "6" => Ok(JokerCard::Six),
+            "7" => Ok(JokerCard::Seven),
+            "8" => Ok(JokerCard::Eight),
+            "9" => Ok(JokerCard::Nine),
+            "T" => Ok(JokerCard::Ten),
+            "J" => Ok(JokerCard::Joker),
+            "Q" => Ok(JokerCard::Queen),
+            "K" => Ok(JokerCard::King),
+            "A" => Ok(JokerCard::Ace),
+            _ => Err(eyre!("unknown card '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for Hand<JokerCard> {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 5 {
+            return Err(eyre!("hand length {} must be 5", s.len()));
+        }
+        let cards = s.chars().map(|c| c.to_string().parse::<JokerCard>()).collect::<Result<Vec<_>>>()?;
+        let mut counts = HashMap::new();
+        let mut jokers = 0;
+        for card in cards.iter() {
+            match card {
+                JokerCard::Joker => jokers += 1,
+                _ => *counts.entry(*card).or_insert(0) += 1,
+            };
+        }
+        let kind = hand_kind(counts.into_values().collect(), jokers);
+        Ok(Hand { kind, cards} )
+    }
+}
+
+fn parse_hands<T>(source: Source) -> Result<Vec<(Hand<T>, u32)>>
+where
+    Hand<T>: FromStr<Err = Report>,
+{
+    let mut hands = Vec::new();
+    for line in source.body().lines() {
+        let (hand_str, ranking_str) =
+            line.split_once(' ').ok_or_else(|| source.error(line, "expected '<hand> <bid>'"))?;
+        let hand = source.parse::<Hand<T>>(hand_str)?;
+        let ranking: u32 = source.parse(ranking_str)?;
+        hands.push((hand, ranking));
+    }
+    Ok(hands)
+}
+
+fn winnings<T: Clone + Ord>(hands: &[(Hand<T>, u32)]) -> usize {
+    let rankings: BTreeMap<_, _> = hands.iter().cloned().collect();
+    rankings.into_iter().enumerate().map(|(i, (_, bet))| (i + 1) * bet as usize).sum()
+}
+
+pub struct Camel {
+    hands: Vec<(Hand<Card>, u32)>,
+    joker_hands: Vec<(Hand<JokerCard>, u32)>,
+}
+
+impl<'a> Solution<'a> for Camel {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Camel {
+            hands: parse_hands(Source::new(fname, body))?,
+            joker_hands: parse_hands(Source::new(fname, body))?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(winnings(&self.hands) as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(winnings(&self.joker_hands) as u64)
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+fn name<R: Rng + ?Sized>(rng: &mut R, last: &[u8]) -> String {
+    let letter = |rng: &mut R| char::from(rng.gen_range(b'B'..=b'Y'));
+    let first = letter(rng);
+    let second = letter(rng);
+    format!("{first}{second}{}", char::from(*last.choose(rng).unwrap_or(&b'X')))
+}
+
+/// Generates `size` directions and one ghost path per ten steps of `size`, up to six.
+///
+/// Each path runs from its `..A` node to its `..Z` node and then cycles back to the node
+/// after the start, so the distance to the goal repeats as the solver assumes. The first
+/// path runs from `AAA` to `ZZZ`.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let directions: String = (0..size).map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' }).collect();
+    let mut used = HashSet::new();
+    let mut fresh = |rng: &mut R, last: &[u8]| loop {
+        let n = name(rng, last);
+        if used.insert(n.clone()) {
+            break n;
+        }
+    };
+    let mut nodes = Vec::new();
+    for ghost in 0..(1 + size / 10).min(6) {
+        let (start, goal) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (fresh(rng, b"A"), fresh(rng, b"Z"))
+        };
+        let middle: Vec<_> = (0..rng.gen_range(1..=(2 * size).min(1000))).map(|_| fresh(rng, b"BCDEFGHIJKLMNOPQRSTUVWXY")).collect();
+        let mut path = vec![start.clone()];
+        path.extend(middle.iter().cloned());
+        path.push(goal.clone());
+        for pair in path.windows(2) {
+            nodes.push((pair[0].clone(), pair[1].clone()));
+        }
+        nodes.push((goal, middle[0].clone()));
+    }
+    nodes.shuffle(rng);
+    let mut out = format!("{directions}\n\n");
+    for (node, next) in nodes {
+        out.push_str(&format!("{node} = ({next}, {next})\n"));
+    }
+    out
+}
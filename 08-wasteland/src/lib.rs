@@ -0,0 +1,96 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common::diagnostic::Source;
+use common::solution::Solution;
+use common::trace;
+use eyre::{bail, eyre, Report, Result};
+use num::Integer;
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(eyre!("unknown direction '{}'", s)),
+        }
+    }
+}
+
+fn solve(directions: &[Direction], map: &HashMap<&str, [&str; 2]>, start: &str, goals: &[&str]) -> Result<usize> {
+    let mut pos = map.get_key_value(start).ok_or_else(|| eyre!("no key '{}'", start))?;
+    // Past this many steps some (node, direction) state has repeated without reaching a goal.
+    let limit = directions.len() * map.len();
+    for (steps, dir) in directions.iter().cycle().enumerate() {
+        if goals.contains(pos.0) {
+            return Ok(steps);
+        }
+        if steps > limit {
+            bail!("'{}' never reaches a goal", start);
+        }
+        let dest = pos.1[*dir as usize];
+        pos = map.get_key_value(dest).ok_or_else(|| eyre!("no key '{}'", dest))?;
+    }
+    bail!("no directions")
+}
+
+pub struct Wasteland<'a> {
+    directions: Vec<Direction>,
+    map: HashMap<&'a str, [&'a str; 2]>,
+}
+
+impl<'a> Solution<'a> for Wasteland<'a> {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let mut lines = body.lines();
+        let line = lines.next().ok_or_else(|| source.eof("missing directions"))?;
+        let directions: Vec<_> = line
+            .char_indices()
+            .map(|(i, c)| source.parse::<Direction>(&line[i..i + c.len_utf8()]))
+            .collect::<Result<_>>()?;
+        if directions.is_empty() {
+            return Err(source.error(line, "missing directions"));
+        }
+        let re = Regex::new(r"^(\w+) = \((\w+), (\w+)\)\s*$")?;
+        lines.next().ok_or_else(|| source.eof("missing map"))?;
+        let mut map = HashMap::new();
+        for line in lines {
+            let captures = re.captures(line).ok_or_else(|| source.error(line, "expected 'AAA = (BBB, CCC)'"))?;
+            map.insert(
+                captures.get(1).map_or("", |m| m.as_str()),
+                [captures.get(2).map_or("", |m| m.as_str()), captures.get(3).map_or("", |m| m.as_str())],
+            );
+        }
+        Ok(Wasteland { directions, map })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        solve(&self.directions, &self.map, "AAA", &["ZZZ"]).map(|s| s as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let goals: Vec<_> = self.map.keys().filter(|k| k.ends_with('Z')).copied().collect();
+        let steps2: Vec<_> = self
+            .map
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|k| solve(&self.directions, &self.map, k, goals.as_slice()))
+            .collect::<Result<_>>()?;
+        trace!("{:?}", steps2);
+        steps2.into_iter().reduce(|acc, s| acc.lcm(&s)).map(|s| s as u64).ok_or_else(|| eyre!("no starting nodes"))
+    }
+}
@@ -0,0 +1,17 @@
+use rand::Rng;
+
+/// Generates `size` sequences of polynomial values, of degree at most five.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.gen_range(5..=21);
+        let degree = rng.gen_range(0..=5usize.min(len - 2));
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-9..=9)).collect();
+        let values: Vec<_> = (0..len as i64)
+            .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c).to_string())
+            .collect();
+        out.push_str(&values.join(" "));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,39 @@
+pub mod generator;
+
+use common::diagnostic::Source;
+use common::sequence::extrapolate;
+use common::solution::Solution;
+use eyre::Result;
+
+pub struct Mirage {
+    sequences: Vec<Vec<i64>>,
+}
+
+impl<'a> Solution<'a> for Mirage {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let sequences = body
+            .lines()
+            .map(|l| {
+                let seq: Vec<_> = l.split_whitespace().map(|t| source.parse::<i64>(t)).collect::<Result<_>>()?;
+                if seq.len() >= 2 {
+                    Ok(seq)
+                } else {
+                    Err(source.error(l, "too few numbers"))
+                }
+            })
+            .collect::<Result<_>>()?;
+        Ok(Mirage { sequences })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        Ok(self.sequences.iter().map(|s| extrapolate(s).1).sum())
+    }
+
+    fn part2(&self) -> Result<i64> {
+        Ok(self.sequences.iter().map(|s| extrapolate(s).0).sum())
+    }
+}
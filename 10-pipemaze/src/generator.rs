@@ -0,0 +1,58 @@
+use common::geometry::Direction;
+use common::random;
+use ndarray::Array2;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// The pipe joining two directions, which must differ.
+fn pipe(a: Direction, b: Direction) -> char {
+    match (a.min(b), a.max(b)) {
+        (Direction::Up, Direction::Down) => '|',
+        (Direction::Right, Direction::Left) => '-',
+        (Direction::Up, Direction::Right) => 'L',
+        (Direction::Up, Direction::Left) => 'J',
+        (Direction::Down, Direction::Left) => '7',
+        (Direction::Right, Direction::Down) => 'F',
+        _ => '.',
+    }
+}
+
+fn connects(c: char, dir: Direction) -> bool {
+    Direction::ALL.into_iter().any(|other| other != dir && pipe(dir, other) == c)
+}
+
+/// Generates a `size + 1` square map holding one random loop among stray pipes.
+///
+/// The loop is the outline of a random region, so it never touches itself, and no stray
+/// pipe points into the start.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let (first, moves) = random::outline(&random::region(rng, size, size));
+    let dim = (size + 1, size + 1);
+    let mut map = Array2::from_shape_fn(dim, |_| if rng.gen_bool(0.5) { '.' } else { *PIPES.choose(rng).unwrap_or(&'.') });
+    let mut corners = Vec::with_capacity(moves.len());
+    let mut pos = first;
+    for (i, &dir) in moves.iter().enumerate() {
+        let came = moves[(i + moves.len() - 1) % moves.len()].reverse();
+        map[pos] = pipe(came, dir);
+        corners.push(pos);
+        pos = dir.offset(pos, 1, dim).unwrap_or(first);
+    }
+    let start = *corners.choose(rng).unwrap_or(&first);
+    map[start] = 'S';
+    for dir in Direction::ALL {
+        if let Some(next) = dir.offset(start, 1, dim) {
+            if !corners.contains(&next) && connects(map[next], dir.reverse()) {
+                map[next] = '.';
+            }
+        }
+    }
+    let mut out = String::new();
+    for row in map.rows() {
+        out.extend(row.iter());
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,186 @@
+pub mod generator;
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use common::diagnostic::Source;
+use common::geometry::Direction;
+use common::grid;
+use common::render::{self, Color, Render, Style};
+use common::solution::Solution;
+use eyre::{eyre, Report, Result};
+use ndarray::{Array2, ArrayView2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pipe {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Start,
+    Ground
+}
+
+impl Pipe {
+    fn glyph(&self) -> char {
+        match self {
+            Pipe::NorthSouth => '│',
+            Pipe::EastWest => '─',
+            Pipe::NorthEast => '└',
+            Pipe::NorthWest => '┘',
+            Pipe::SouthWest => '┐',
+            Pipe::SouthEast => '┌',
+            Pipe::Start => 'S',
+            Pipe::Ground => '.',
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        matches!(self, Pipe::NorthSouth | Pipe::NorthEast | Pipe::NorthWest)
+    }
+
+    /// The two directions a pipe segment leads, or `None` for ground and the start.
+    fn connections(&self) -> Option<[Direction; 2]> {
+        match self {
+            Pipe::NorthSouth => Some([Direction::Up, Direction::Down]),
+            Pipe::EastWest => Some([Direction::Right, Direction::Left]),
+            Pipe::NorthEast => Some([Direction::Up, Direction::Right]),
+            Pipe::NorthWest => Some([Direction::Up, Direction::Left]),
+            Pipe::SouthWest => Some([Direction::Down, Direction::Left]),
+            Pipe::SouthEast => Some([Direction::Down, Direction::Right]),
+            Pipe::Start | Pipe::Ground => None,
+        }
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Pipe::Start),
+            '.' => Ok(Pipe::Ground),
+            '|' => Ok(Pipe::NorthSouth),
+            '-' => Ok(Pipe::EastWest),
+            'L' => Ok(Pipe::NorthEast),
+            'J' => Ok(Pipe::NorthWest),
+            '7' => Ok(Pipe::SouthWest),
+            'F' => Ok(Pipe::SouthEast),
+            _ => Err(eyre!("unknown pipe '{c}'"))
+        }
+    }
+}
+
+fn from_start(map: ArrayView2<Pipe>, start: [usize; 2]) -> Option<Pipe> {
+    let open: Vec<_> = Direction::ALL
+        .into_iter()
+        .filter(|&dir| {
+            dir.offset(start, 1, map.dim())
+                .and_then(|pos| map[pos].connections())
+                .is_some_and(|c| c.contains(&dir.reverse()))
+        })
+        .collect();
+    if open.len() != 2 {
+        return None;
+    }
+    [Pipe::NorthSouth, Pipe::EastWest, Pipe::NorthEast, Pipe::NorthWest, Pipe::SouthWest, Pipe::SouthEast]
+        .into_iter()
+        .find(|p| p.connections().is_some_and(|c| c.iter().all(|d| open.contains(d))))
+}
+
+fn find_loop(fname: &str, body: &str) -> Result<Pipemaze> {
+    let mut map = grid::parse::<Pipe>(fname, body)?;
+    let start = map
+        .indexed_iter()
+        .find_map(|((row, col), &p)| if p == Pipe::Start { Some([row, col]) } else { None })
+        .ok_or_else(|| Source::new(fname, body).eof("no start found"))?;
+    map[start] = from_start(map.view(), start)
+        .ok_or_else(|| Source::new(fname, body).error_at(start[0] + 1, start[1] + 1, "start does not join exactly two pipes"))?;
+    let mut edges = HashSet::from([start]);
+    let mut stack = VecDeque::from([start]);
+    while let Some(pos) = stack.pop_front() {
+        let [row, col] = pos;
+        let connections = map[pos]
+            .connections()
+            .ok_or_else(|| eyre!("index ({}, {}) is {:?}!", row, col, map[pos]))?;
+        for dir in connections {
+            let next = dir
+                .offset(pos, 1, map.dim())
+                .ok_or_else(|| eyre!("loop leaves the map at ({}, {})", row, col))?;
+            if edges.insert(next) {
+                stack.push_back(next);
+            }
+        }
+    }
+    Ok(Pipemaze { map, start, edges })
+}
+
+pub struct Pipemaze {
+    map: Array2<Pipe>,
+    start: [usize; 2],
+    edges: HashSet<[usize; 2]>,
+}
+
+impl<'a> Solution<'a> for Pipemaze {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        find_loop(fname, body)
+    }
+
+    fn part1(&self) -> Result<u64> {
+        if render::enabled() {
+            render::show("loop", draw(&self.shown(), self.start, &[]));
+        }
+        Ok(self.edges.len() as u64 / 2)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let inside = enclosed(&self.map, &self.edges);
+        if render::enabled() {
+            render::show("enclosed tiles", draw(&self.shown(), self.start, &inside));
+        }
+        Ok(inside.len() as u64)
+    }
+}
+
+impl Pipemaze {
+    /// The loop's pipes as box drawing characters, leaving out every other pipe.
+    fn shown(&self) -> Array2<char> {
+        Array2::from_shape_fn(self.map.dim(), |(r, c)| {
+            if self.edges.contains(&[r, c]) { self.map[[r, c]].glyph() } else { ' ' }
+        })
+    }
+}
+
+fn draw(shown: &Array2<char>, start: [usize; 2], inside: &[[usize; 2]]) -> String {
+    Render::new(shown.view(), |&c| Style::colored(c, Color::Yellow))
+        .mark([start], Color::Red, 'S')
+        .mark(inside.iter().copied(), Color::Green, 'I')
+        .to_string()
+}
+
+fn enclosed(map: &Array2<Pipe>, edges: &HashSet<[usize; 2]>) -> Vec<[usize; 2]> {
+    let (rows, columns) = map.dim();
+    let mut inside = Vec::new();
+    // A point is inside a closed shape if a ray in any direction crosses an odd
+    // number of times.
+    // Trick: Need to only count up or down, not both, when casting left -> right.
+    for row in 0..rows {
+        for col in 0..columns {
+            if !edges.contains(&[row, col]) {
+                let crossings = (0..col).filter(|&c| match edges.get(&[row, c]) {
+                    Some(pos) => map[*pos].is_up(),
+                    None => false
+                }).count();
+                if crossings & 1 == 1 {
+                    inside.push([row, col]);
+                }
+            }
+        }
+    }
+    inside
+}
@@ -0,0 +1,12 @@
+use rand::Rng;
+
+/// Generates a `size` square image where about one pixel in twenty is a galaxy.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| if rng.gen_bool(0.05) { '#' } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,82 @@
+pub mod generator;
+
+use common::grid::Grid;
+use common::solution::Solution;
+use eyre::{eyre, Result};
+use itertools::Itertools;
+use ndarray::Array2;
+
+fn sum_distances(universe: &Array2<bool>, expansion: usize) -> isize {
+    let empty_cols: Vec<_> = universe
+        .columns()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, r)| {
+            if r.into_iter().all(|g| !g) {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .collect();
+    let empty_rows: Vec<_> = universe
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, r)| {
+            if r.into_iter().all(|g| !g) {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .collect();
+    let galaxies: Vec<_> = universe
+        .indexed_iter()
+        .filter_map(|((r0, c0), g)| {
+            if *g {
+                let r = empty_rows.iter().take_while(|r| **r < r0).count() * expansion + r0;
+                let c = empty_cols.iter().take_while(|c| **c < c0).count() * expansion + c0;
+                Some((r, c))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let mut sum = 0isize;
+    for combo in galaxies.iter().combinations(2) {
+        let &(g1x, g1y) = combo[0];
+        let &(g2x, g2y) = combo[1];
+        let d = ((g1x as isize) - (g2x as isize)).abs() + ((g1y as isize) - (g2y as isize)).abs();
+        //println!("({}, {}) -> ({}, {}): {}", g1x, g1y, g2x, g2y, d);
+        sum += d;
+    }
+    sum
+}
+
+pub struct Galaxies {
+    universe: Array2<bool>,
+}
+
+impl<'a> Solution<'a> for Galaxies {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let universe = Grid::with_parser(fname, body, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(eyre!("unexpected char '{c}'")),
+        })
+        .single()?;
+        Ok(Galaxies { universe })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(u64::try_from(sum_distances(&self.universe, 1))?)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(u64::try_from(sum_distances(&self.universe, 999_999))?)
+    }
+}
@@ -0,0 +1,34 @@
+use rand::Rng;
+
+/// Generates `size` records of up to sixteen springs, each with at least one broken run.
+///
+/// A random arrangement is drawn first and then some springs are hidden behind `?`, so
+/// every record has at least one solution.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.gen_range(2..=16);
+        let mut springs = String::new();
+        let mut runs = Vec::new();
+        while springs.len() < len {
+            let gap = if springs.is_empty() { rng.gen_range(0..=2) } else { rng.gen_range(1..=3) };
+            let run = rng.gen_range(1..=5);
+            if springs.len() + gap + run > len {
+                break;
+            }
+            springs.extend(std::iter::repeat_n('.', gap));
+            springs.extend(std::iter::repeat_n('#', run));
+            runs.push(run.to_string());
+        }
+        if runs.is_empty() {
+            springs = "#".to_string();
+            runs.push("1".to_string());
+        }
+        while springs.len() < len {
+            springs.push('.');
+        }
+        let hidden: String = springs.chars().map(|c| if rng.gen_bool(0.5) { '?' } else { c }).collect();
+        out.push_str(&format!("{hidden} {}\n", runs.join(",")));
+    }
+    out
+}
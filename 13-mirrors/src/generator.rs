@@ -0,0 +1,100 @@
+use ndarray::Array2;
+use rand::Rng;
+
+/// A reflection line: after column `index` when vertical, otherwise after row `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Line {
+    vertical: bool,
+    index: usize,
+}
+
+impl Line {
+    fn random<R: Rng + ?Sized>(rng: &mut R, rows: usize, cols: usize) -> Self {
+        let vertical = rng.gen_bool(0.5);
+        let len = if vertical { cols } else { rows };
+        Line { vertical, index: rng.gen_range(0..len - 1) }
+    }
+
+    /// Every pair of cells the line reflects onto each other.
+    fn pairs(self, rows: usize, cols: usize) -> Vec<([usize; 2], [usize; 2])> {
+        let (len, across) = if self.vertical { (cols, rows) } else { (rows, cols) };
+        let reach = (self.index + 1).min(len - self.index - 1);
+        let mut pairs = Vec::new();
+        for k in 0..reach {
+            let (a, b) = (self.index - k, self.index + 1 + k);
+            for x in 0..across {
+                pairs.push(if self.vertical { ([x, a], [x, b]) } else { ([a, x], [b, x]) });
+            }
+        }
+        pairs
+    }
+
+    fn all(rows: usize, cols: usize) -> impl Iterator<Item = Line> {
+        let vertical = (0..cols - 1).map(|index| Line { vertical: true, index });
+        let horizontal = (0..rows - 1).map(|index| Line { vertical: false, index });
+        vertical.chain(horizontal)
+    }
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    parent[i] = root;
+    root
+}
+
+fn mismatches(pattern: &Array2<bool>, line: Line) -> usize {
+    let (rows, cols) = pattern.dim();
+    line.pairs(rows, cols).into_iter().filter(|&(a, b)| pattern[a] != pattern[b]).count()
+}
+
+/// Tries to build a pattern reflected exactly about one line and about a second line
+/// except for a single smudge.
+fn pattern<R: Rng + ?Sized>(rng: &mut R) -> Option<Array2<bool>> {
+    let (rows, cols) = (rng.gen_range(5..=15), rng.gen_range(5..=15));
+    let clean = Line::random(rng, rows, cols);
+    let smudged = Line::random(rng, rows, cols);
+    if clean == smudged {
+        return None;
+    }
+    let smudged_pairs = smudged.pairs(rows, cols);
+    let smudge = smudged_pairs[rng.gen_range(0..smudged_pairs.len())];
+    let id = |[r, c]: [usize; 2]| r * cols + c;
+    let mut parent: Vec<usize> = (0..rows * cols).collect();
+    for (a, b) in clean.pairs(rows, cols).into_iter().chain(smudged_pairs.into_iter().filter(|&p| p != smudge)) {
+        let (ra, rb) = (find(&mut parent, id(a)), find(&mut parent, id(b)));
+        parent[ra] = rb;
+    }
+    let (ra, rb) = (find(&mut parent, id(smudge.0)), find(&mut parent, id(smudge.1)));
+    if ra == rb {
+        return None;
+    }
+    let mut values: Vec<bool> = (0..rows * cols).map(|_| rng.gen_bool(0.5)).collect();
+    values[rb] = !values[ra];
+    let pattern = Array2::from_shape_fn((rows, cols), |(r, c)| values[find(&mut parent, id([r, c]))]);
+    let unique = Line::all(rows, cols).all(|line| match mismatches(&pattern, line) {
+        0 => line == clean,
+        1 => line == smudged,
+        _ => true,
+    });
+    unique.then_some(pattern)
+}
+
+/// Generates `size` patterns, each with one reflection line and one smudge that reveals
+/// a different line.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut patterns = Vec::new();
+    while patterns.len() < size.max(1) {
+        if let Some(pattern) = pattern(rng) {
+            let mut text = String::new();
+            for row in pattern.rows() {
+                text.extend(row.iter().map(|&rock| if rock { '#' } else { '.' }));
+                text.push('\n');
+            }
+            patterns.push(text);
+        }
+    }
+    patterns.join("\n")
+}
@@ -0,0 +1,180 @@
+#![allow(clippy::iter_nth_zero)]
+
+pub mod generator;
+
+use common::grid::Grid;
+use common::render::{self, Color, Render, Style};
+use common::solution::Solution;
+use eyre::{eyre, Report, Result, OptionExt};
+use ndarray::{Array2, ArrayBase, Axis, Data, Ix2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terrain {
+    Ash,
+    Rocks,
+}
+
+impl Terrain {
+    fn flip(&mut self) {
+        *self = match self {
+            Terrain::Ash => Terrain::Rocks,
+            Terrain::Rocks => Terrain::Ash
+        };
+    }
+}
+impl TryFrom<char> for Terrain {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Terrain::Ash),
+            '#' => Ok(Terrain::Rocks),
+            _ => Err(eyre!("unknown terrain '{c}'")),
+        }
+    }
+}
+
+/// Draws a pattern with the rows or columns either side of a reflection line highlighted.
+fn draw(array: &Array2<Terrain>, (axis, index): (Axis, usize), smudge: Option<[usize; 2]>) -> String {
+    let (rows, cols) = array.dim();
+    let line: Vec<_> = match axis {
+        Axis(0) => (0..cols).flat_map(|c| [[index, c], [index + 1, c]]).collect(),
+        _ => (0..rows).flat_map(|r| [[r, index], [r, index + 1]]).collect(),
+    };
+    Render::new(array.view(), |t| Style::plain(if *t == Terrain::Rocks { '#' } else { '.' }))
+        .highlight(line, Color::Cyan)
+        .mark(smudge, Color::Red, 'X')
+        .to_string()
+}
+
+fn mirror<'a, T: PartialEq + 'a, D: Data<Elem = T>>(array: &'a ArrayBase<D, Ix2>) -> impl Iterator<Item=(Axis, usize)> + 'a {
+
+    let col_iter = array.columns().into_iter();
+    let col_iter_s1 = col_iter.clone().skip(1);
+    let col_combined = col_iter.zip(col_iter_s1).enumerate().filter_map(|(i, (c0, c1))| {
+        let (_, cols) = array.dim();
+        if c0 == c1 && (0..i).rev().zip((i+2)..cols).all(|(lc, rc)| array.column(lc) == array.column(rc)) {
+            Some((Axis(1), i))
+        } else {
+            None
+        }
+    });
+    let row_iter = array.rows().into_iter();
+    let row_iter_s1 = row_iter.clone().skip(1);
+    let row_combined = row_iter.zip(row_iter_s1).enumerate().filter_map(|(i, (r0, r1))| {
+        let (rows, _) = array.dim();
+        if r0 == r1 && (0..i).rev().zip((i + 2)..rows).all(|(lr, rr)| array.row(lr) == array.row(rr)) {
+            Some((Axis(0), i))
+        } else {
+            None
+        }
+    });
+    col_combined.chain(row_combined)
+}
+
+pub struct Mirrors {
+    patterns: Vec<Array2<Terrain>>,
+}
+
+impl<'a> Solution<'a> for Mirrors {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Mirrors {
+            patterns: Grid::new(fname, body).collect::<Result<_>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut sum = 0usize;
+        for array in self.patterns.iter() {
+            let answer = mirror(array).nth(0).ok_or_eyre("did not find mirror")?;
+            if render::enabled() {
+                render::show("reflection", draw(array, answer, None));
+            }
+            sum += match answer {
+                (Axis(0), x) => 100 * (x + 1),
+                (Axis(1), y) => y + 1,
+                _ => unreachable!(),
+            };
+        }
+        Ok(sum as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut arrays = self.patterns.clone();
+        let mut sum2 = 0usize;
+        'array: for array in arrays.iter_mut() {
+            let answer = mirror(array).nth(0).ok_or_eyre("did not find mirror")?;
+            let (rows, cols) = array.dim();
+            for row in 0..rows {
+                for col in 0..cols {
+                    array[[row, col]].flip();
+                    if let Some(new) = mirror(array).filter(|a| *a != answer).nth(0) {
+                        if render::enabled() {
+                            render::show("smudge", draw(array, new, Some([row, col])));
+                        }
+                        sum2 += match new {
+                            (Axis(0), x) => 100 * (x + 1),
+                            (Axis(1), y) => y+1,
+                            _ => unreachable!()
+                        };
+                        continue 'array;
+                    }
+                    array[[row, col]].flip();
+                }
+            }
+            // sum2 += match *answer {
+            //     (Axis(0), x) => 100 * (x + 1),
+            //     (Axis(1), y) => y+1,
+            //     _ => unreachable!()
+            // };
+        }
+        Ok(sum2 as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Checks every line directly against the definition of a reflection.
+    fn brute_force(array: &Array2<bool>) -> Vec<(Axis, usize)> {
+        let (rows, cols) = array.dim();
+        let cols_found = (0..cols.saturating_sub(1))
+            .filter(|&i| (0..=i).rev().zip(i + 1..cols).all(|(l, r)| (0..rows).all(|row| array[[row, l]] == array[[row, r]])))
+            .map(|i| (Axis(1), i));
+        let rows_found = (0..rows.saturating_sub(1))
+            .filter(|&i| (0..=i).rev().zip(i + 1..rows).all(|(t, b)| (0..cols).all(|col| array[[t, col]] == array[[b, col]])))
+            .map(|i| (Axis(0), i));
+        cols_found.chain(rows_found).collect()
+    }
+
+    fn pattern() -> impl Strategy<Value = Array2<bool>> {
+        (1usize..8, 1usize..8).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(any::<bool>(), rows * cols)
+                .prop_map(move |v| Array2::from_shape_vec((rows, cols), v).unwrap())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn mirror_matches_brute_force(array in pattern()) {
+            prop_assert_eq!(mirror(&array).collect::<Vec<_>>(), brute_force(&array));
+        }
+
+        #[test]
+        fn mirror_finds_planted_reflection(array in pattern(), flip in any::<bool>()) {
+            let mut planted = ndarray::concatenate![Axis(1), array, array.slice(ndarray::s![.., ..;-1])];
+            if flip {
+                planted = planted.reversed_axes();
+            }
+            let found: Vec<_> = mirror(&planted).collect();
+            prop_assert_eq!(&found, &brute_force(&planted));
+            prop_assert!(!found.is_empty());
+        }
+    }
+}
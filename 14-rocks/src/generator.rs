@@ -0,0 +1,16 @@
+use rand::Rng;
+
+/// Generates a `size` square platform of round and cube rocks.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| match rng.gen_range(0..20) {
+            0..=3 => 'O',
+            4..=6 => '#',
+            _ => '.',
+        }));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,212 @@
+#![allow(clippy::mut_range_bound)]
+
+pub mod generator;
+
+use common::diagnostic::Source;
+use common::grid;
+use common::image;
+use common::render::{self, Color, Render, Style};
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, OptionExt, Report, Result};
+use indexmap::{IndexMap, map::Entry};
+use ndarray::{s, Array2, ArrayBase, DataMut, Ix2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Rock {
+    Round,
+    Cube,
+    Empty
+}
+
+impl TryFrom<char> for Rock {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Rock::Empty),
+            '#' => Ok(Rock::Cube),
+            'O' => Ok(Rock::Round),
+            _ => Err(eyre!("unknown rock '{c}'"))
+        }
+    }
+}
+
+fn draw(platform: &Array2<Rock>) -> Render<'_, Rock> {
+    Render::new(platform.view(), |r| match r {
+        Rock::Round => Style::colored('O', Color::Yellow),
+        Rock::Cube => Style::colored('#', Color::Blue),
+        Rock::Empty => Style::plain('.'),
+    })
+}
+
+fn color(rock: &Rock) -> [u8; 3] {
+    match rock {
+        Rock::Round => [230, 180, 40],
+        Rock::Cube => [40, 60, 120],
+        Rock::Empty => [235, 235, 235],
+    }
+}
+
+fn cycle<D: DataMut<Elem=Rock>>(array: &mut ArrayBase<D, Ix2>) {
+    let (rows, cols) = array.dim();
+    // North
+    for mut col in array.columns_mut() {
+        let mut start = col.iter().position(|&r| r == Rock::Empty).unwrap_or(cols);
+        let mut end = col.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(cols - start) + start;
+        while start < cols {
+            for i in start..end {
+                if col[i] == Rock::Round {
+                    col[start] = Rock::Round;
+                    col[i] = Rock::Empty;
+                    start += 1;
+                }
+            }
+            start = col.slice(s![end..]).iter().position(|&r| r == Rock::Empty).unwrap_or(cols - end) + end;
+            end = col.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(cols - start) + start;
+        }
+    }
+    // West
+    for mut row in array.rows_mut() {
+        let mut start = row.iter().position(|&r| r == Rock::Empty).unwrap_or(rows);
+        let mut end = row.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(cols - start) + start;
+        while start < rows {
+            for i in start..end {
+                if row[i] == Rock::Round {
+                    row[start] = Rock::Round;
+                    row[i] = Rock::Empty;
+                    start += 1;
+                }
+            }
+            start = row.slice(s![end..]).iter().position(|&r| r == Rock::Empty).unwrap_or(rows - end) + end;
+            end = row.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(rows - start) + start;
+        }
+    }
+    // South
+    for mut col in array.columns_mut() {
+        let mut start = cols - 1 - col.iter().rev().position(|&r| r == Rock::Empty).unwrap_or(cols - 1);
+        while start > 0 {
+            let end = start - 1 - col.slice(s![..start;-1]).iter().position(|&r| r == Rock::Cube).unwrap_or(start - 1);
+            for i in (end..start).rev() {
+                if col[i] == Rock::Round {
+                    col[start] = Rock::Round;
+                    col[i] = Rock::Empty;
+                    start -= 1;
+                }
+            }
+            if end > 0 {
+                start = end - 1 - col.slice(s![..end;-1]).iter().position(|&r| r == Rock::Empty).unwrap_or(end - 1);
+            } else {
+                start = 0;
+            }
+        }
+    }
+    // East
+    for mut row in array.rows_mut() {
+        let mut start = rows - 1 - row.iter().rev().position(|&r| r == Rock::Empty).unwrap_or(rows - 1);
+        while start > 0 {
+            let end = start - 1 - row.slice(s![..start;-1]).iter().position(|&r| r == Rock::Cube).unwrap_or(start -1);
+            for i in (end..start).rev() {
+                if row[i] == Rock::Round {
+                    row[start] = Rock::Round;
+                    row[i] = Rock::Empty;
+                    start -= 1;
+                }
+            }
+            if end > 0 {
+                start = end - 1 - row.slice(s![..end;-1]).iter().position(|&r| r == Rock::Empty).unwrap_or(end - 1);
+            } else {
+                start = 0;
+            }
+        }
+    }
+}
+
+pub struct Rocks {
+    platform: Array2<Rock>,
+}
+
+impl<'a> Solution<'a> for Rocks {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let platform: Array2<Rock> = grid::parse(fname, body)?;
+        let (rows, cols) = platform.dim();
+        // Tilting in every direction assumes the platform is square.
+        if rows != cols {
+            let msg = format!("platform must be square but is {rows}x{cols}");
+            return Err(Source::new(fname, body).error_at(1, 1, msg));
+        }
+        Ok(Rocks { platform })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut array = self.platform.clone();
+        let (rows, _) = array.dim();
+        for mut col in array.columns_mut() {
+            let mut start = col.iter().position(|&r| r == Rock::Empty).unwrap_or(rows);
+            let mut end = col.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(rows - start) + start;
+            while start < rows {
+                for i in start..end {
+                    if col[i] == Rock::Round {
+                        col[start] = Rock::Round;
+                        col[i] = Rock::Empty;
+                        start += 1;
+                    }
+                }
+                start = col.slice(s![end..]).iter().position(|&r| r == Rock::Empty).unwrap_or(rows - end) + end;
+                end = col.slice(s![start..]).iter().position(|&r| r == Rock::Cube).unwrap_or(rows - start) + start;
+            }
+        }
+        if render::enabled() {
+            render::show("tilted north", draw(&array));
+        }
+        let sum: usize = array.rows().into_iter().enumerate().map(|(i, r)| (rows - i) * r.iter().filter(|&&r| r == Rock::Round).count()).sum();
+        Ok(sum as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut part2 = self.platform.clone();
+        let (rows, _) = part2.dim();
+        let mut map = IndexMap::new();
+        let mut index = 0;
+        let frames = image::frames();
+        if let Some(dir) = &frames {
+            image::write_frame(dir, 0, part2.view(), color)?;
+        }
+        for n in 1..=1_000_000_000 {
+            cycle(&mut part2);
+            if let Some(dir) = &frames {
+                image::write_frame(dir, n, part2.view(), color)?;
+            }
+            let l = map.len();
+            match map.entry(part2.clone()) {
+                Entry::Occupied(e) => {
+                    trace!("Found: {} {l}", e.index());
+                    let whole = (1_000_000_000 - l - 1) / (l - e.index());
+                    let mod1 = (1_000_000_000 - l - 1) % (l - e.index());
+                    trace!("whole: {whole}\tmod1: {mod1}");
+                    trace!("{}, {}, {}", whole % (l - e.index()), mod1 % (l - e.index()), (whole + mod1) % (l - e.index()));
+                    index = mod1 + e.index();
+                    break;
+                },
+                Entry::Vacant(e) => e.insert(()),
+            };
+        }
+        //let x = map[index];
+        let last = map.get_index(index).ok_or_eyre("no cycle found")?.0;
+        if render::enabled() {
+            render::show("after 1000000000 cycles", draw(last));
+        }
+        if let Some(path) = image::output() {
+            image::write(&path, last.view(), color)?;
+        }
+        let sum2: usize = last.rows().into_iter().enumerate().map(|(i, r)| (rows - i) * r.iter().filter(|&&r| r == Rock::Round).count()).sum();
+        for (k, _) in map {
+            trace!("{}", k.rows().into_iter().enumerate().map(|(i, r)| (rows - i) * r.iter().filter(|&&r| r == Rock::Round).count()).sum::<usize>());
+        }
+        trace!("Sum: {sum2} index: {index}");
+        Ok(sum2 as u64)
+    }
+}
@@ -0,0 +1,20 @@
+use rand::Rng;
+
+/// Generates `size` steps over a small pool of labels, so lenses are often replaced and removed.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size.div_ceil(4))
+        .map(|_| (0..rng.gen_range(1..=6)).map(|_| char::from(rng.gen_range(b'a'..=b'z'))).collect())
+        .collect();
+    let steps: Vec<_> = (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+    format!("{}\n", steps.join(","))
+}
@@ -0,0 +1,14 @@
+use rand::Rng;
+
+const DEVICES: [char; 4] = ['/', '\\', '-', '|'];
+
+/// Generates a `size` square contraption where about one tile in ten holds a mirror or splitter.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| if rng.gen_bool(0.1) { DEVICES[rng.gen_range(0..4)] } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
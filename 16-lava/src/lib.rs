@@ -0,0 +1,151 @@
+pub mod generator;
+
+use std::collections::HashSet;
+
+use common::geometry::Direction;
+use common::grid;
+use common::image;
+use common::render::{self, Color, Render, Style};
+use common::solution::Solution;
+use eyre::{eyre, Report, Result};
+use ndarray::{s, Array2, ArrayBase, Data, Ix2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Empty,
+    MirrorLeftUp,
+    MirrorLeftDown,
+    SplitHoriz,
+    SplitVert,
+}
+
+impl TryFrom<char> for Entry {
+    type Error = Report;
+
+    fn try_from(c: char) -> std::prelude::v1::Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Entry::Empty),
+            '/' => Ok(Entry::MirrorLeftUp),
+            '\\' => Ok(Entry::MirrorLeftDown),
+            '-' => Ok(Entry::SplitHoriz),
+            '|' => Ok(Entry::SplitVert),
+            _ => Err(eyre!("unknown entry '{c}'")),
+        }
+    }
+}
+
+/// Marks every tile a beam passes through after entering at `start` heading in `dir`.
+fn energize<D: Data<Elem = Entry>>(
+    array: &ArrayBase<D, Ix2>,
+    start: [isize; 2],
+    dir: Direction,
+) -> Array2<bool> {
+    let mut occupied = Array2::<bool>::default(array.dim());
+    let mut cast = HashSet::new();
+    let mut rays = vec![(start, dir)];
+    while let Some((ray, dir)) = rays.pop() {
+        cast.insert((ray, dir));
+        let s = match dir {
+            Direction::Right => s![ray[0], ray[1] + 1..],
+            Direction::Down => s![ray[0] + 1.., ray[1]],
+            Direction::Left => s![ray[0], ..ray[1];-1],
+            Direction::Up => s![..ray[0];-1, ray[1]],
+        };
+        let entry_slice = array.slice(s);
+        let mut occupied_slice = occupied.slice_mut(s);
+        for (offset, (entry, occ)) in entry_slice.iter().zip(occupied_slice.iter_mut()).enumerate()
+        {
+            let offset = offset + 1;
+            *occ = true;
+            let next = match (entry, dir.is_vertical()) {
+                (Entry::MirrorLeftUp, false) | (Entry::MirrorLeftDown, true) => [Some(dir.turn_left()), None],
+                (Entry::MirrorLeftUp, true) | (Entry::MirrorLeftDown, false) => [Some(dir.turn_right()), None],
+                (Entry::SplitVert, false) => [Some(Direction::Up), Some(Direction::Down)],
+                (Entry::SplitHoriz, true) => [Some(Direction::Left), Some(Direction::Right)],
+                _ => [None, None],
+            };
+            if next[0].is_some() {
+                let pos = dir.shift(ray, offset as isize);
+                for nd in next.into_iter().flatten() {
+                    if !cast.contains(&(pos, nd)) {
+                        rays.push((pos, nd));
+                    }
+                }
+                break;
+            }
+        }
+    }
+    occupied
+}
+
+fn occupy<D: Data<Elem = Entry>>(array: &ArrayBase<D, Ix2>, start: [isize; 2], dir: Direction) -> usize {
+    energize(array, start, dir).into_iter().filter(|&e| e).count()
+}
+
+/// Draws the wall with energized tiles highlighted, and shown as `#` where they are empty.
+fn draw(wall: &Array2<Entry>, energized: &Array2<bool>) -> String {
+    let cells: Vec<_> = energized.indexed_iter().filter_map(|((r, c), &e)| e.then_some([r, c])).collect();
+    let empty = cells.iter().copied().filter(|&p| wall[p] == Entry::Empty);
+    Render::new(wall.view(), |e| {
+        Style::plain(match e {
+            Entry::Empty => '.',
+            Entry::MirrorLeftUp => '/',
+            Entry::MirrorLeftDown => '\\',
+            Entry::SplitHoriz => '-',
+            Entry::SplitVert => '|',
+        })
+    })
+    .highlight(cells.iter().copied(), Color::Yellow)
+    .mark(empty, Color::Yellow, '#')
+    .to_string()
+}
+
+pub struct Lava {
+    wall: Array2<Entry>,
+}
+
+impl<'a> Solution<'a> for Lava {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Lava {
+            wall: grid::parse(fname, body)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let image = image::output();
+        if render::enabled() || image.is_some() {
+            let energized = energize(&self.wall, [0, -1], Direction::Right);
+            if render::enabled() {
+                render::show("energized", draw(&self.wall, &energized));
+            }
+            if let Some(path) = image {
+                let tiles = ndarray::Zip::from(&self.wall).and(&energized).map_collect(|&e, &lit| (e, lit));
+                image::write(&path, tiles.view(), |&(entry, lit)| match (entry, lit) {
+                    (Entry::Empty, true) => [255, 200, 40],
+                    (Entry::Empty, false) => [24, 24, 32],
+                    (_, true) => [255, 255, 255],
+                    (_, false) => [110, 110, 130],
+                })?;
+            }
+        }
+        Ok(occupy(&self.wall, [0, -1], Direction::Right) as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let wall = &self.wall;
+        let (rows, cols) = wall.dim();
+        let rows_iter = (0..rows as isize).flat_map(|r| {
+            [
+                occupy(wall, [r, -1], Direction::Right),
+                occupy(wall, [r, cols as isize], Direction::Left),
+            ]
+        });
+        let cols_iter = (0..cols as isize).flat_map(|c| {
+            [occupy(wall, [-1, c], Direction::Down), occupy(wall, [rows as isize, c], Direction::Up)]
+        });
+        rows_iter.chain(cols_iter).max().map(|n| n as u64).ok_or_else(|| eyre!("empty grid"))
+    }
+}
@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Generates a `size` square map of heat losses, at least five blocks wide so the ultra
+/// crucible can reach the far corner.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(5);
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(1..=9))));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,70 @@
+use common::geometry::Direction;
+use common::random;
+use rand::Rng;
+
+/// A straight run of the outline, covering the grid rows or columns `start..end`.
+struct Run {
+    dir: Direction,
+    start: usize,
+    end: usize,
+}
+
+impl Run {
+    fn length(&self, rows: &[usize], cols: &[usize]) -> usize {
+        let widths = if self.dir.is_vertical() { rows } else { cols };
+        widths[self.start..self.end].iter().sum()
+    }
+}
+
+fn runs(first: [usize; 2], moves: &[Direction]) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    let mut pos = [first[0] as isize, first[1] as isize];
+    for &dir in moves {
+        let next = dir.shift(pos, 1);
+        let axis = if dir.is_vertical() { 0 } else { 1 };
+        let cell = pos[axis].min(next[axis]) as usize;
+        match runs.last_mut() {
+            Some(run) if run.dir == dir => {
+                run.start = run.start.min(cell);
+                run.end = run.end.max(cell + 1);
+            }
+            _ => runs.push(Run { dir, start: cell, end: cell + 1 }),
+        }
+        pos = next;
+    }
+    runs
+}
+
+/// Generates a dig plan outlining a random region of a `size` square grid, at most 255.
+///
+/// Both the plan and the one hidden in the colors trace the same simple polygon, with
+/// every row and column of the grid stretched by a random amount. The plain plan stays
+/// small enough to fill in directly.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.clamp(1, 255);
+    let (first, moves) = random::outline(&random::region(rng, size, size));
+    let runs = runs(first, &moves);
+    let small = (255 / size).min(10);
+    let widths = |rng: &mut R, max: usize| (0..size).map(|_| rng.gen_range(1..=max)).collect::<Vec<_>>();
+    let (rows, cols) = (widths(rng, small), widths(rng, small));
+    let (big_rows, big_cols) = (widths(rng, 0xfffff / size), widths(rng, 0xfffff / size));
+    let mut out = String::new();
+    for run in runs.iter() {
+        let letter = match run.dir {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        };
+        let digit = match run.dir {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        let steps = run.length(&rows, &cols);
+        let hidden = run.length(&big_rows, &big_cols);
+        out.push_str(&format!("{letter} {steps} (#{hidden:05x}{digit})\n"));
+    }
+    out
+}
@@ -0,0 +1,231 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common::diagnostic::Source;
+use common::geometry::Direction;
+use common::image;
+use common::render::{self, Color, Render, Style};
+use common::solution::Solution;
+use eyre::{eyre, Report, Result, OptionExt};
+use ndarray::{Array2, s, DataMut, ArrayBase, Ix2};
+use rgb::{RGB,RGB8};
+
+trait Arrow {
+    fn dir(&self) -> Direction;
+    fn steps(&self) -> usize;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dig {
+    dir: Direction,
+    steps: u8,
+    color: RGB8
+}
+
+impl Arrow for Dig {
+    fn dir(&self) -> Direction { self.dir }
+    fn steps(&self) -> usize { usize::from(self.steps) }
+}
+
+impl FromStr for Dig {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let dir_str = tokens.next().ok_or_eyre("missing direction")?;
+        let steps_str = tokens.next().ok_or_eyre("missing steps")?;
+        let color_str = tokens.next().ok_or_eyre("missing color")?;
+        let dir = dir_str.parse()?;
+        let steps = steps_str.parse()?;
+        let color_hex = hex::decode(color_str.strip_prefix("(#").and_then(|s| s.strip_suffix(')')).ok_or_eyre("invalid color format '{color_str}'")?)?;
+        let [r, g, b] = <[u8; 3]>::try_from(color_hex).map_err(|_| eyre!("color must have 3 bytes"))?;
+        let color = RGB8::new(r, g, b);
+        Ok(Dig { dir, steps, color })
+    }
+}
+
+fn flood_fill<T: Default, D: DataMut<Elem=Option<T>>>(
+    array: &mut ArrayBase<D, Ix2>,
+    edges: &HashMap<usize, Vec<usize>>,
+) {
+    let (rows, cols) = array.dim();
+    for row in 0..rows {
+        for col in 0..cols {
+            if array[[row, col]].is_none() {
+                let count = edges.get(&row).map_or(0, |r| r.iter().filter(|&&c| c > col).count());
+                if count & 1 == 1 {
+                    array[[row, col]] = Some(Default::default());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BigDig {
+    dir: Direction,
+    steps: usize,
+}
+
+impl Arrow for BigDig {
+    fn dir(&self) -> Direction { self.dir}
+    fn steps(&self) -> usize {self.steps}
+}
+
+impl TryFrom<Dig> for BigDig {
+    type Error = Report;
+
+    fn try_from(value: Dig) -> Result<Self> {
+        let color: RGB<usize> = RGB::new(value.color.r.into(), value.color.g.into(), value.color.b.into());
+        let steps = (color.r << 12) + (color.g << 4) + (color.b >> 4);
+        let dir = match color.b & 0x0F {
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
+            d => return Err(eyre!("invalid direction digit {d}")),
+        };
+        Ok(BigDig {dir, steps})
+    }
+}
+
+
+fn solve<A: Arrow>(digs: &[A]) -> usize {
+    let mut pos = [0isize; 2];
+    let mut vertices = vec![pos];
+    let mut perimeter = 0usize;
+    for dig in digs.iter() {
+        perimeter += dig.steps();
+        let steps = dig.steps() as isize;
+        pos = dig.dir().shift(pos, steps);
+        vertices.push(pos);
+    }
+    let mut area = 0.0f64;
+    for (v1, v2) in vertices.iter().zip(vertices.iter().cycle().skip(1)) {
+        let v1_x: f64 = v1[1] as f64;
+        let v1_y: f64 = v1[0] as f64;
+        let v2_x: f64 = v2[1] as f64;
+        let v2_y: f64 = v2[0] as f64;
+        area += v1_x * v2_y;
+        area -= v1_y * v2_x;
+    }
+    area = area.abs() / 2.0;
+    (area + (perimeter as f64 / 2.0)) as usize + 1
+}
+
+
+
+fn parse_digs(source: Source) -> Result<Vec<Dig>> {
+    source.body().lines().map(|l| source.parse(l)).collect()
+}
+
+pub struct Lagoon {
+    digs: Vec<Dig>,
+}
+
+impl<'a> Solution<'a> for Lagoon {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        Ok(Lagoon {
+            digs: parse_digs(Source::new(fname, body))?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let digs = &self.digs;
+        let extent = |dir| digs.iter().filter(|d| d.dir == dir).map(|d| usize::from(d.steps)).sum::<usize>();
+        let (up, left) = (extent(Direction::Up), extent(Direction::Left));
+        let rows = up + extent(Direction::Down) + 1;
+        let cols = left + extent(Direction::Right) + 1;
+        let mut pos = [up, left];
+        let mut array = Array2::<Option<RGB8>>::default((rows, cols));
+        let mut edges = HashMap::new();
+        for (i, dig) in digs.iter().enumerate() {
+            let end = dig.dir.offset(pos, usize::from(dig.steps), array.dim()).ok_or_eyre("dig leaves the lagoon")?;
+            let slice = match dig.dir {
+                Direction::Up => array.slice_mut(s![end[0]..pos[0], pos[1]]),
+                Direction::Down => array.slice_mut(s![pos[0]..=end[0], pos[1]]),
+                Direction::Left => array.slice_mut(s![pos[0], end[1]..pos[1]]),
+                Direction::Right => array.slice_mut(s![pos[0], pos[1]..=end[1]]),
+            };
+            for x in slice {
+                *x = Some(dig.color);
+            }
+            let range = match dig.dir {
+                Direction::Up => end[0]+1..pos[0],
+                Direction::Down => pos[0]+1..end[0],
+                _ => 0..0,
+            };
+            for row in range {
+                edges.entry(row).or_insert_with(Vec::new).push(pos[1]);
+            }
+            match (dig.dir, digs[(i + 1) % digs.len()].dir) {
+                (Direction::Left|Direction::Right, Direction::Up) => edges.entry(pos[0]).or_insert(Vec::new()).push(end[1]),
+                (Direction::Down, Direction::Left|Direction::Right) => edges.entry(end[0]).or_insert(Vec::new()).push(pos[1]),
+                _ => (),
+            };
+            pos = end;
+        }
+        let image = image::output();
+        let trench = (render::enabled() || image.is_some()).then(|| array.clone());
+        flood_fill(&mut array, &edges);
+        if let Some(trench) = trench {
+            // The lagoon is sized for the worst case, so only draw around the trench.
+            let dug: Vec<_> = trench.indexed_iter().filter_map(|((r, c), x)| x.map(|_| [r, c])).collect();
+            let top = dug.iter().map(|p| p[0]).min().unwrap_or(0);
+            let bottom = dug.iter().map(|p| p[0]).max().unwrap_or(0);
+            let left = dug.iter().map(|p| p[1]).min().unwrap_or(0);
+            let right = dug.iter().map(|p| p[1]).max().unwrap_or(0);
+            let window = s![top..=bottom, left..=right];
+            if render::enabled() {
+                let inside = array
+                    .indexed_iter()
+                    .filter(|&((r, c), x)| x.is_some() && trench[[r, c]].is_none())
+                    .map(|((r, c), _)| [r - top, c - left]);
+                let drawing = Render::new(trench.slice(window), |x| match x {
+                    Some(color) => Style::colored('#', Color::Rgb(color.r, color.g, color.b)),
+                    None => Style::plain('.'),
+                })
+                .mark(inside, Color::Blue, '~');
+                render::show("trench", drawing);
+            }
+            if let Some(path) = image {
+                let lagoon = ndarray::Zip::from(trench.slice(window)).and(array.slice(window)).map_collect(|&t, &x| (t, x.is_some()));
+                image::write(&path, lagoon.view(), |&(trench, inside)| match (trench, inside) {
+                    (Some(color), _) => [color.r, color.g, color.b],
+                    (None, true) => [32, 64, 128],
+                    (None, false) => [240, 240, 240],
+                })?;
+            }
+        }
+        let count: usize = array.iter().filter(|x| x.is_some()).count();
+        Ok(count as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let bigdigs: Vec<BigDig> = self.digs.iter().copied().map(BigDig::try_from).collect::<Result<_>>()?;
+        Ok(solve(&bigdigs) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn shoelace_matches_flood_fill(seed in any::<u64>(), size in 1usize..12) {
+            let body = generator::input(&mut StdRng::seed_from_u64(seed), size);
+            let lagoon = Lagoon::parse("generated", &body).unwrap();
+            prop_assert_eq!(lagoon.part1().unwrap(), solve(&lagoon.digs) as u64, "{}", body);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// Picks where a rule sends parts: accept, reject or any workflow after `index`, so the
+/// workflows never loop.
+fn destination<R: Rng + ?Sized>(rng: &mut R, names: &[String], index: usize) -> String {
+    match rng.gen_range(0..4) {
+        0 => "A".to_string(),
+        1 => "R".to_string(),
+        _ if index + 1 < names.len() => names[rng.gen_range(index + 1..names.len())].clone(),
+        _ => if rng.gen_bool(0.5) { "A" } else { "R" }.to_string(),
+    }
+}
+
+/// Generates `size` workflows, starting from `in`, and `size` parts to sort.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut used = HashSet::from(["in".to_string()]);
+    let mut names = vec!["in".to_string()];
+    while names.len() < size {
+        let name: String = (0..rng.gen_range(2..=3)).map(|_| char::from(rng.gen_range(b'a'..=b'z'))).collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    let mut out = String::new();
+    for (index, name) in names.iter().enumerate() {
+        let mut rules: Vec<_> = (0..rng.gen_range(1..=4))
+            .map(|_| {
+                let category = CATEGORIES[rng.gen_range(0..4)];
+                let op = if rng.gen_bool(0.5) { '<' } else { '>' };
+                let value = rng.gen_range(2..4000);
+                format!("{category}{op}{value}:{}", destination(rng, &names, index))
+            })
+            .collect();
+        rules.push(destination(rng, &names, index));
+        out.push_str(&format!("{name}{{{}}}\n", rules.join(",")));
+    }
+    out.push('\n');
+    for _ in 0..size {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        out.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+    out
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a broadcaster feeding two to four binary counters, shaped like the puzzle inputs.
+///
+/// Each counter is a chain of `size` flip-flops (at least two, at most twelve) read by a
+/// conjunction. The conjunction watches the bits set in the counter's period, an odd number
+/// with the top bit set, and when they are all on resets the counter and pulses an
+/// inverter. The inverters feed the conjunction in front of `rx`.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let bits = size.clamp(2, 12);
+    let mut used = HashSet::from(["rx".to_string()]);
+    let mut fresh = |rng: &mut R| loop {
+        let name: String = (0..2).map(|_| char::from(rng.gen_range(b'a'..=b'z'))).collect();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+    let last = fresh(rng);
+    let mut starts = Vec::new();
+    let mut lines = Vec::new();
+    let mut inverters = Vec::new();
+    for _ in 0..rng.gen_range(2..=4) {
+        let period = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+        let flops: Vec<_> = (0..bits).map(|_| fresh(rng)).collect();
+        let counter = fresh(rng);
+        let inverter = fresh(rng);
+        let mut resets = vec![flops[0].clone()];
+        for (bit, flop) in flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(counter.clone());
+            } else {
+                resets.push(flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{flop} -> {}", outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        resets.shuffle(rng);
+        lines.push(format!("&{counter} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(flops[0].clone());
+        inverters.push(inverter);
+    }
+    lines.push(format!("&{last} -> rx"));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    let mut body = lines.join("\n");
+    body.push('\n');
+    body
+}
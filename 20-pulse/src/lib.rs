@@ -0,0 +1,211 @@
+pub mod generator;
+
+use std::collections::{HashMap, VecDeque};
+
+use common::diagnostic::Source;
+use common::dot;
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, Result};
+use num::Integer;
+use petgraph::dot::{Config, Dot};
+use petgraph::Graph;
+
+/// Presses tried while waiting for each input of the module that feeds `rx`.
+const MAX_PRESSES: u64 = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A destination no line defines, such as `rx`, which only receives pulses.
+    Output,
+}
+
+#[derive(Debug, Clone)]
+struct Module<'a> {
+    name: &'a str,
+    kind: Kind,
+    /// Destinations, each with this module's slot among the destination's inputs.
+    outputs: Vec<(usize, usize)>,
+    inputs: Vec<usize>,
+}
+
+/// What changes between presses: whether each flip-flop is on, and the last pulse each
+/// conjunction remembers from each of its inputs.
+#[derive(Debug, Clone)]
+struct State {
+    on: Vec<bool>,
+    memory: Vec<Vec<bool>>,
+}
+
+pub struct Pulse<'a> {
+    modules: Vec<Module<'a>>,
+    broadcaster: usize,
+}
+
+impl<'a> Pulse<'a> {
+    fn index(&self, name: &str) -> Option<usize> {
+        self.modules.iter().position(|m| m.name == name)
+    }
+
+    fn state(&self) -> State {
+        State {
+            on: vec![false; self.modules.len()],
+            memory: self.modules.iter().map(|m| vec![false; m.inputs.len()]).collect(),
+        }
+    }
+
+    /// Pushes the button once, calling `sent(from, to, high)` for every pulse in the order
+    /// they are delivered. The button's own low pulse comes first, from the broadcaster to itself.
+    fn press(&self, state: &mut State, mut sent: impl FnMut(usize, usize, bool)) {
+        let mut queue = VecDeque::from([(self.broadcaster, self.broadcaster, 0, false)]);
+        while let Some((from, to, slot, high)) = queue.pop_front() {
+            sent(from, to, high);
+            let module = &self.modules[to];
+            let out = match module.kind {
+                Kind::Broadcaster => high,
+                Kind::FlipFlop if high => continue,
+                Kind::FlipFlop => {
+                    state.on[to] = !state.on[to];
+                    state.on[to]
+                }
+                Kind::Conjunction => {
+                    state.memory[to][slot] = high;
+                    !state.memory[to].iter().all(|&h| h)
+                }
+                Kind::Output => continue,
+            };
+            queue.extend(module.outputs.iter().map(|&(next, slot)| (to, next, slot, out)));
+        }
+    }
+
+    /// The module graph, with flip-flops as boxes and conjunctions as diamonds.
+    fn graph(&self) -> Graph<String, &str> {
+        let mut graph = Graph::new();
+        let nodes: Vec<_> = self
+            .modules
+            .iter()
+            .map(|m| {
+                let prefix = match m.kind {
+                    Kind::FlipFlop => "%",
+                    Kind::Conjunction => "&",
+                    Kind::Broadcaster | Kind::Output => "",
+                };
+                graph.add_node(format!("{prefix}{}", m.name))
+            })
+            .collect();
+        for (i, m) in self.modules.iter().enumerate() {
+            for &(next, _) in &m.outputs {
+                graph.add_edge(nodes[i], nodes[next], "");
+            }
+        }
+        graph
+    }
+}
+
+fn parse_modules<'a>(source: &Source<'a>) -> Result<Pulse<'a>> {
+    let mut modules = Vec::new();
+    let mut destinations = Vec::new();
+    let mut index = HashMap::new();
+    for line in source.body().lines() {
+        let (lhs, rhs) = line.split_once(" -> ").ok_or_else(|| source.error(line, "expected 'module -> destinations'"))?;
+        let (kind, name) = match lhs.as_bytes().first() {
+            Some(b'%') => (Kind::FlipFlop, &lhs[1..]),
+            Some(b'&') => (Kind::Conjunction, &lhs[1..]),
+            _ if lhs == "broadcaster" => (Kind::Broadcaster, lhs),
+            _ => return Err(source.error(lhs, "expected '%', '&' or 'broadcaster'")),
+        };
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(source.error(lhs, format!("invalid module name '{name}'")));
+        }
+        if index.insert(name, modules.len()).is_some() {
+            return Err(source.error(name, format!("module '{name}' is defined twice")));
+        }
+        let targets: Vec<_> = rhs.split(',').map(str::trim).collect();
+        if let Some(bad) = targets.iter().find(|t| t.is_empty() || !t.bytes().all(|b| b.is_ascii_alphanumeric())) {
+            return Err(source.error(rhs, format!("invalid destination '{bad}'")));
+        }
+        modules.push(Module { name, kind, outputs: Vec::new(), inputs: Vec::new() });
+        destinations.push(targets);
+    }
+    let broadcaster = *index.get("broadcaster").ok_or_else(|| source.eof("no broadcaster"))?;
+    for (from, targets) in destinations.into_iter().enumerate() {
+        for name in targets {
+            let to = *index.entry(name).or_insert_with(|| {
+                modules.push(Module { name, kind: Kind::Output, outputs: Vec::new(), inputs: Vec::new() });
+                modules.len() - 1
+            });
+            let slot = modules[to].inputs.len();
+            modules[to].inputs.push(from);
+            modules[from].outputs.push((to, slot));
+        }
+    }
+    Ok(Pulse { modules, broadcaster })
+}
+
+impl<'a> Solution<'a> for Pulse<'a> {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        parse_modules(&Source::new(fname, body))
+    }
+
+    /// With `--dot`, saves the module graph, with flip-flops as boxes and conjunctions as diamonds.
+    fn export(&self) -> Result<()> {
+        if let Some(path) = dot::output() {
+            let graph = self.graph();
+            dot::write(&path, Dot::with_attr_getters(&graph, &[Config::EdgeNoLabel], &|_, _| String::new(), &|_, (_, label)| {
+                match label.as_bytes()[0] {
+                    b'%' => "shape = box".to_string(),
+                    b'&' => "shape = diamond".to_string(),
+                    _ => "shape = ellipse".to_string(),
+                }
+            }))?;
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut state = self.state();
+        let (mut low, mut high) = (0u64, 0u64);
+        for _ in 0..1000 {
+            self.press(&mut state, |_, _, h| if h { high += 1 } else { low += 1 });
+        }
+        Ok(low * high)
+    }
+
+    /// `rx` is fed by a single conjunction, whose inputs each send it a high pulse on a
+    /// cycle of their own. The first press that sends all of them at once is the LCM of
+    /// their periods, where, as in the puzzle inputs, each period starts from the first press.
+    fn part2(&self) -> Result<u64> {
+        let rx = self.index("rx").ok_or_else(|| eyre!("no module sends to rx"))?;
+        let feeder = match self.modules[rx].inputs[..] {
+            [feeder] if self.modules[feeder].kind == Kind::Conjunction => feeder,
+            _ => return Err(eyre!("rx is not fed by a single conjunction")),
+        };
+        let inputs = &self.modules[feeder].inputs;
+        let mut periods: Vec<Option<u64>> = vec![None; inputs.len()];
+        let mut state = self.state();
+        let mut presses = 0;
+        while periods.iter().any(Option::is_none) {
+            presses += 1;
+            if presses > MAX_PRESSES {
+                let slot = periods.iter().position(Option::is_none).unwrap_or(0);
+                let name = self.modules[inputs[slot]].name;
+                return Err(eyre!("{name} sent no high pulse to {} in {MAX_PRESSES} presses", self.modules[feeder].name));
+            }
+            self.press(&mut state, |from, to, high| {
+                if to == feeder && high {
+                    if let Some(slot) = inputs.iter().position(|&i| i == from) {
+                        periods[slot].get_or_insert(presses);
+                    }
+                }
+            });
+        }
+        trace!("periods into {}: {periods:?}", self.modules[feeder].name);
+        Ok(periods.into_iter().flatten().fold(1, |acc, p| acc.lcm(&p)))
+    }
+}
@@ -0,0 +1 @@
+common::main!(pulse::Pulse);
@@ -0,0 +1,29 @@
+//! Answers for the examples in the puzzle text.
+
+use common::solution::Solution;
+use eyre::Result;
+use pulse::Pulse;
+
+fn example(name: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(format!("{}/{name}", env!("CARGO_MANIFEST_DIR")))?)
+}
+
+#[test]
+fn examples() -> Result<()> {
+    // The examples have no rx module, so only part 1 has an answer.
+    for (name, answer) in [("example.txt", 32000000), ("example2.txt", 11687500)] {
+        let body = example(name)?;
+        let solution = Pulse::parse(name, &body)?;
+        assert_eq!(solution.part1()?, answer, "{name}");
+        assert!(solution.part2().is_err(), "{name}");
+    }
+    Ok(())
+}
+
+#[test]
+fn rx_fires_when_every_cycle_lines_up() -> Result<()> {
+    // `ia` sends `hub` a high pulse every 2 presses and `ib` every 4.
+    let body = "broadcaster -> a, b\n%a -> ia\n&ia -> hub\n%b -> b2\n%b2 -> ib\n&ib -> hub\n&hub -> rx\n";
+    assert_eq!(Pulse::parse("rx.txt", body)?.part2()?, 4);
+    Ok(())
+}
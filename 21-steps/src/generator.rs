@@ -0,0 +1,23 @@
+use rand::Rng;
+
+/// Generates a square garden `2 * size + 1` plots wide with the start in the middle.
+///
+/// Like the puzzle inputs, the border and the start's row and column are free of rocks,
+/// so walks cross the repeated garden at a steady rate.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let width = 2 * size.clamp(2, 100) + 1;
+    let middle = width / 2;
+    let mut body = String::new();
+    for row in 0..width {
+        for col in 0..width {
+            let open = row == 0 || col == 0 || row == width - 1 || col == width - 1 || row == middle || col == middle;
+            body.push(match () {
+                _ if row == middle && col == middle => 'S',
+                _ if !open && rng.gen_bool(0.2) => '#',
+                _ => '.',
+            });
+        }
+        body.push('\n');
+    }
+    body
+}
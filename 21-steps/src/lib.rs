@@ -0,0 +1,155 @@
+pub mod generator;
+
+use std::collections::HashSet;
+
+use common::diagnostic::Source;
+use common::geometry::Direction;
+use common::grid;
+use common::sequence;
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, Report, Result};
+use ndarray::Array2;
+
+/// Samples taken one garden width apart before giving up on the growth turning quadratic.
+const MAX_SAMPLES: u64 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Garden,
+    Rock,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Garden),
+            '#' => Ok(Tile::Rock),
+            'S' => Ok(Tile::Start),
+            _ => Err(eyre!("unknown tile '{c}'")),
+        }
+    }
+}
+
+/// Breadth first search from the start, extended one step at a time as needed.
+struct Walk<'a> {
+    map: &'a Array2<Tile>,
+    tiled: bool,
+    seen: HashSet<[isize; 2]>,
+    frontier: Vec<[isize; 2]>,
+    /// Number of plots first reached after each number of steps.
+    counts: Vec<u64>,
+}
+
+impl<'a> Walk<'a> {
+    fn new(map: &'a Array2<Tile>, start: [usize; 2], tiled: bool) -> Self {
+        let start = start.map(|x| x as isize);
+        Walk { map, tiled, seen: HashSet::from([start]), frontier: vec![start], counts: vec![1] }
+    }
+
+    fn is_garden(&self, [row, col]: [isize; 2]) -> bool {
+        let (rows, cols) = self.map.dim();
+        let (rows, cols) = (rows as isize, cols as isize);
+        if !self.tiled && (!(0..rows).contains(&row) || !(0..cols).contains(&col)) {
+            return false;
+        }
+        self.map[[row.rem_euclid(rows) as usize, col.rem_euclid(cols) as usize]] != Tile::Rock
+    }
+
+    /// Plots that can be reached in exactly `steps` steps: those first reached in fewer
+    /// steps of the same parity, since the walk can step back and forth to use up the rest.
+    fn reachable(&mut self, steps: usize) -> u64 {
+        while self.counts.len() <= steps && !self.frontier.is_empty() {
+            let mut next = Vec::new();
+            for pos in std::mem::take(&mut self.frontier) {
+                for dir in Direction::ALL {
+                    let step = dir.shift(pos, 1);
+                    if self.is_garden(step) && self.seen.insert(step) {
+                        next.push(step);
+                    }
+                }
+            }
+            self.counts.push(next.len() as u64);
+            self.frontier = next;
+        }
+        self.counts.iter().take(steps + 1).skip(steps % 2).step_by(2).sum()
+    }
+}
+
+/// Whether the last three second differences agree, so the samples grow quadratically.
+fn is_quadratic(samples: &[i64]) -> bool {
+    let Some(tail) = samples.len().checked_sub(5).map(|i| &samples[i..]) else {
+        return false;
+    };
+    let second: Vec<_> = tail.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+    second.iter().all(|&d| d == second[0])
+}
+
+pub struct Steps {
+    map: Array2<Tile>,
+    start: [usize; 2],
+}
+
+impl Steps {
+    /// Plots the elf can end on after exactly `steps` steps inside the garden.
+    pub fn reachable(&self, steps: usize) -> u64 {
+        Walk::new(&self.map, self.start, false).reachable(steps)
+    }
+
+    /// Plots the elf can end on after exactly `steps` steps when the garden repeats forever.
+    ///
+    /// Samples the count every garden width, at the step counts congruent to `steps`. Once
+    /// the garden's repeats are crossed at a steady rate those samples fit a quadratic, which
+    /// is then extrapolated out to `steps`.
+    pub fn reachable_tiled(&self, steps: u64) -> Result<u64> {
+        let (rows, cols) = self.map.dim();
+        if rows != cols {
+            return Err(eyre!("the garden is {rows}x{cols}, but must be square to repeat evenly"));
+        }
+        let width = rows as u64;
+        let mut walk = Walk::new(&self.map, self.start, true);
+        let mut samples = Vec::new();
+        for k in 0..MAX_SAMPLES {
+            let at = steps % width + k * width;
+            samples.push(walk.reachable(at as usize) as i64);
+            if at == steps {
+                return Ok(samples[samples.len() - 1] as u64);
+            }
+            if is_quadratic(&samples) {
+                trace!("quadratic after {} samples: {samples:?}", samples.len());
+                let last = &samples[samples.len() - 3..];
+                let index = (steps - at) / width + 2;
+                let index = i64::try_from(index).map_err(|_| eyre!("{steps} steps is too far to extrapolate"))?;
+                return Ok(sequence::value_at(last, index) as u64);
+            }
+        }
+        Err(eyre!("the plots reached every {width} steps never grew quadratically"))
+    }
+}
+
+impl<'a> Solution<'a> for Steps {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let mut map = grid::parse::<Tile>(fname, body)?;
+        let mut starts = map.indexed_iter().filter(|(_, &t)| t == Tile::Start).map(|((r, c), _)| [r, c]);
+        let start = starts.next().ok_or_else(|| Source::new(fname, body).eof("no start found"))?;
+        if let Some([r, c]) = starts.next() {
+            return Err(Source::new(fname, body).error_at(r + 1, c + 1, "second start"));
+        }
+        map[start] = Tile::Garden;
+        Ok(Steps { map, start })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(self.reachable(64))
+    }
+
+    fn part2(&self) -> Result<u64> {
+        self.reachable_tiled(26501365)
+    }
+}
@@ -0,0 +1 @@
+common::main!(steps::Steps);
@@ -0,0 +1,16 @@
+//! Plot counts given for the example in the puzzle text.
+
+use common::solution::Solution;
+use eyre::Result;
+use steps::Steps;
+
+#[test]
+fn example() -> Result<()> {
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt"))?;
+    let steps = Steps::parse("example.txt", &body)?;
+    assert_eq!(steps.reachable(6), 16);
+    for (n, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004), (1000, 668697), (5000, 16733044)] {
+        assert_eq!(steps.reachable_tiled(n)?, plots, "{n} steps");
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+/// Generates `4 * size` bricks up to four cubes long, scattered without overlapping over
+/// a column of air `size + 2` cubes wide and deep.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.clamp(1, 100) as u32;
+    let side = size + 2;
+    let mut filled = HashSet::new();
+    let mut body = String::new();
+    let mut placed = 0;
+    while placed < 4 * size {
+        let axis = rng.gen_range(0..3);
+        let length = rng.gen_range(0..4);
+        let mut min = [rng.gen_range(0..side), rng.gen_range(0..side), rng.gen_range(1..=8 * size)];
+        min[axis] = min[axis].saturating_sub(length).max(if axis == 2 { 1 } else { 0 });
+        let mut max = min;
+        max[axis] += length;
+        if axis < 2 && max[axis] >= side {
+            continue;
+        }
+        let cubes: Vec<_> = (0..=length).map(|i| {
+            let mut cube = min;
+            cube[axis] += i;
+            cube
+        }).collect();
+        if cubes.iter().any(|c| filled.contains(c)) {
+            continue;
+        }
+        filled.extend(cubes);
+        body.push_str(&format!("{},{},{}~{},{},{}\n", min[0], min[1], min[2], max[0], max[1], max[2]));
+        placed += 1;
+    }
+    body
+}
@@ -0,0 +1,158 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common::diagnostic::Source;
+use common::dump;
+use common::render::{Color, Render, Style};
+use common::solution::Solution;
+use eyre::{eyre, Report, Result};
+use ndarray::Array2;
+use petgraph::{Directed, Direction, Graph};
+use petgraph::graph::NodeIndex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Brick {
+    min: [u32; 3],
+    max: [u32; 3],
+}
+
+impl Brick {
+    /// The x, y columns the brick covers when seen from above.
+    fn footprint(&self) -> impl Iterator<Item = [u32; 2]> + '_ {
+        (self.min[0]..=self.max[0]).flat_map(move |x| (self.min[1]..=self.max[1]).map(move |y| [x, y]))
+    }
+}
+
+impl FromStr for Brick {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s.split_once('~').ok_or_else(|| eyre!("expected two ends separated by '~'"))?;
+        let end = |e: &str| -> Result<[u32; 3]> {
+            let coords: Vec<u32> = e.split(',').map(|c| c.parse()).collect::<Result<_, _>>()?;
+            coords.try_into().map_err(|_| eyre!("expected x,y,z in '{e}'"))
+        };
+        let (min, max) = (end(a)?, end(b)?);
+        if (0..3).any(|i| min[i] > max[i]) {
+            return Err(eyre!("the first end must be the lowest on every axis"));
+        }
+        if (0..3).filter(|&i| min[i] != max[i]).count() > 1 {
+            return Err(eyre!("brick is not a straight line"));
+        }
+        if min[2] == 0 {
+            return Err(eyre!("bricks must start above the ground, at z = 1"));
+        }
+        Ok(Brick { min, max })
+    }
+}
+
+/// Edges run from each brick to each brick resting directly on it.
+type Supports = Graph<(), (), Directed, usize>;
+
+/// Lets every brick fall until it rests on the ground or another brick.
+///
+/// Returns the settled bricks, in order of their lowest z, and which rest on which.
+fn settle(bricks: &[Brick]) -> Result<(Vec<Brick>, Supports)> {
+    let mut settled = bricks.to_vec();
+    settled.sort_by_key(|b| b.min[2]);
+    let mut graph = Graph::with_capacity(settled.len(), settled.len());
+    for _ in &settled {
+        graph.add_node(());
+    }
+    // The height of the top of each column and the brick that reaches it.
+    let mut heights: HashMap<[u32; 2], (u32, usize)> = HashMap::new();
+    for (i, brick) in settled.iter_mut().enumerate() {
+        let top = brick.footprint().filter_map(|p| heights.get(&p)).map(|&(h, _)| h).max().unwrap_or(0);
+        let mut below: Vec<_> = brick.footprint().filter_map(|p| heights.get(&p)).filter(|&&(h, _)| h == top).map(|&(_, j)| j).collect();
+        below.sort_unstable();
+        below.dedup();
+        for j in below {
+            graph.add_edge(NodeIndex::new(j), NodeIndex::new(i), ());
+        }
+        let drop = brick.min[2].checked_sub(top + 1).ok_or_else(|| eyre!("brick {:?}~{:?} overlaps a brick below it", brick.min, brick.max))?;
+        brick.min[2] -= drop;
+        brick.max[2] -= drop;
+        for p in brick.footprint() {
+            heights.insert(p, (brick.max[2], i));
+        }
+    }
+    Ok((settled, graph))
+}
+
+/// Number of other bricks that fall when `brick` is taken away.
+fn chain_reaction(graph: &Supports, brick: usize) -> usize {
+    let mut support: Vec<_> = graph.node_indices().map(|n| graph.neighbors_directed(n, Direction::Incoming).count()).collect();
+    let mut falling = vec![NodeIndex::new(brick)];
+    let mut fallen = 0;
+    while let Some(n) = falling.pop() {
+        for above in graph.neighbors_directed(n, Direction::Outgoing) {
+            support[above.index()] -= 1;
+            if support[above.index()] == 0 {
+                fallen += 1;
+                falling.push(above);
+            }
+        }
+    }
+    fallen
+}
+
+const COLORS: [Color; 6] = [Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta, Color::Cyan];
+
+/// Prints each layer of the settled stack from the ground up, as seen from above.
+///
+/// Bricks are lettered `A` to `Z` in the order they settled, starting over after `Z`.
+fn dump_layers(settled: &[Brick]) {
+    let width = settled.iter().map(|b| b.max[0] as usize + 1).max().unwrap_or(0);
+    let depth = settled.iter().map(|b| b.max[1] as usize + 1).max().unwrap_or(0);
+    let height = settled.iter().map(|b| b.max[2]).max().unwrap_or(0);
+    for z in 1..=height {
+        let mut layer = Array2::<Option<usize>>::default((depth, width));
+        for (i, brick) in settled.iter().enumerate().filter(|(_, b)| (b.min[2]..=b.max[2]).contains(&z)) {
+            for [x, y] in brick.footprint() {
+                layer[[y as usize, x as usize]] = Some(i);
+            }
+        }
+        let drawing = Render::new(layer.view(), |cell| match cell {
+            Some(i) => Style::colored(char::from(b'A' + (i % 26) as u8), COLORS[i % COLORS.len()]),
+            None => Style::plain('.'),
+        });
+        dump::section(&format!("z = {z}"), drawing);
+    }
+}
+
+pub struct Slabs {
+    settled: Vec<Brick>,
+    graph: Supports,
+}
+
+impl<'a> Solution<'a> for Slabs {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let bricks: Vec<Brick> = body.lines().map(|l| source.parse(l)).collect::<Result<_>>()?;
+        let (settled, graph) = settle(&bricks)?;
+        if dump::enabled() {
+            dump_layers(&settled);
+        }
+        Ok(Slabs { settled, graph })
+    }
+
+    /// A brick is safe to disintegrate when everything it holds up rests on another brick too.
+    fn part1(&self) -> Result<u64> {
+        let graph = &self.graph;
+        let safe = graph
+            .node_indices()
+            .filter(|&n| graph.neighbors_directed(n, Direction::Outgoing).all(|above| graph.neighbors_directed(above, Direction::Incoming).count() > 1))
+            .count();
+        Ok(safe as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let sum: usize = (0..self.settled.len()).map(|i| chain_reaction(&self.graph, i)).sum();
+        Ok(sum as u64)
+    }
+}
@@ -0,0 +1 @@
+common::main!(slabs::Slabs);
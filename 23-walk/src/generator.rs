@@ -0,0 +1,55 @@
+use rand::Rng;
+
+/// Generates a trail map shaped like the puzzle inputs: a lattice of up to five by five
+/// junctions joined by straight corridors, with slopes pointing right or down at both ends
+/// of each corridor so that obeying them can only lead towards the end.
+///
+/// Some corridors are left out, but never those along the top and right edges, so the end
+/// can always be reached.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let k = size.clamp(1, 5);
+    let gaps = |rng: &mut R, first| {
+        let mut at = vec![first];
+        for _ in 1..k {
+            at.push(at[at.len() - 1] + rng.gen_range(3..=6));
+        }
+        at
+    };
+    let rows_at = gaps(rng, 2);
+    let cols_at = gaps(rng, 1);
+    let (rows, cols) = (rows_at[k - 1] + 3, cols_at[k - 1] + 2);
+    let mut map = vec![vec!['#'; cols]; rows];
+    for row in &mut map[..=rows_at[0]] {
+        row[cols_at[0]] = '.';
+    }
+    for row in &mut map[rows_at[k - 1]..] {
+        row[cols_at[k - 1]] = '.';
+    }
+    for (i, &r) in rows_at.iter().enumerate() {
+        for (j, &c) in cols_at.iter().enumerate() {
+            map[r][c] = '.';
+            if j + 1 < k && (i == 0 || !rng.gen_bool(0.25)) {
+                let end = cols_at[j + 1];
+                for cell in &mut map[r][c + 1..end] {
+                    *cell = '.';
+                }
+                map[r][c + 1] = '>';
+                map[r][end - 1] = '>';
+            }
+            if i + 1 < k && (j + 1 == k || !rng.gen_bool(0.25)) {
+                let end = rows_at[i + 1];
+                for row in &mut map[r + 1..end] {
+                    row[c] = '.';
+                }
+                map[r + 1][c] = 'v';
+                map[end - 1][c] = 'v';
+            }
+        }
+    }
+    let mut body = String::new();
+    for row in map {
+        body.extend(row);
+        body.push('\n');
+    }
+    body
+}
@@ -0,0 +1,195 @@
+pub mod generator;
+
+use std::collections::HashMap;
+
+use common::diagnostic::Source;
+use common::geometry::Direction;
+use common::grid;
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, OptionExt, Report, Result};
+use ndarray::Array2;
+use petgraph::algo::toposort;
+use petgraph::visit::EdgeRef;
+use petgraph::{Directed, Graph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            '^' => Ok(Tile::Slope(Direction::Up)),
+            '>' => Ok(Tile::Slope(Direction::Right)),
+            'v' => Ok(Tile::Slope(Direction::Down)),
+            '<' => Ok(Tile::Slope(Direction::Left)),
+            _ => Err(eyre!("unknown tile '{c}'")),
+        }
+    }
+}
+
+/// Whether a step in `dir` may leave `from` and enter `to` without going up a slope.
+fn downhill(from: Tile, to: Tile, dir: Direction) -> bool {
+    [from, to].iter().all(|t| !matches!(t, Tile::Slope(d) if *d != dir))
+}
+
+/// The trail map boiled down to its junctions, with an edge per corridor between two of them.
+struct Junctions {
+    start: usize,
+    end: usize,
+    /// Corridors as `(from, to, steps)`, with a flag for whether they can be walked
+    /// that way without climbing a slope.
+    corridors: Vec<(usize, usize, usize, bool)>,
+}
+
+fn open_neighbours(map: &Array2<Tile>, pos: [usize; 2]) -> impl Iterator<Item = (Direction, [usize; 2])> + '_ {
+    Direction::ALL
+        .into_iter()
+        .filter_map(move |dir| dir.offset(pos, 1, map.dim()).map(|next| (dir, next)))
+        .filter(|&(_, next)| map[next] != Tile::Forest)
+}
+
+/// Finds the junctions, where three or more paths meet, plus the start and end, and
+/// follows each corridor leaving them to the junction at its other end.
+fn compress(map: &Array2<Tile>, start: [usize; 2], end: [usize; 2]) -> Junctions {
+    let mut ids = HashMap::from([(start, 0), (end, 1)]);
+    for ((r, c), &tile) in map.indexed_iter() {
+        if tile != Tile::Forest && open_neighbours(map, [r, c]).count() >= 3 {
+            let id = ids.len();
+            ids.entry([r, c]).or_insert(id);
+        }
+    }
+    let mut corridors = Vec::new();
+    for (&junction, &from) in &ids {
+        for (dir, first) in open_neighbours(map, junction) {
+            let (mut prev, mut pos) = (junction, first);
+            let mut steps = 1;
+            let mut walkable = downhill(map[prev], map[pos], dir);
+            let to = loop {
+                if let Some(&to) = ids.get(&pos) {
+                    break Some(to);
+                }
+                let Some((dir, next)) = open_neighbours(map, pos).find(|&(_, n)| n != prev) else {
+                    break None;
+                };
+                walkable &= downhill(map[pos], map[next], dir);
+                (prev, pos) = (pos, next);
+                steps += 1;
+            };
+            if let Some(to) = to.filter(|&to| to != from) {
+                corridors.push((from, to, steps, walkable));
+            }
+        }
+    }
+    Junctions { start: ids[&start], end: ids[&end], corridors }
+}
+
+/// Longest walk from the start to the end when every slope is obeyed, which makes the
+/// junction graph acyclic: the longest distance to each junction follows from those before it.
+///
+/// The corridors by the start and end have no slopes, but a walk never goes back into the
+/// start or out of the end, so those directions are left out.
+fn longest_downhill(junctions: &Junctions) -> Result<usize> {
+    let edges = junctions
+        .corridors
+        .iter()
+        .filter(|&&(from, to, _, walkable)| walkable && to != junctions.start && from != junctions.end)
+        .map(|&(from, to, steps, _)| (from, to, steps));
+    let mut graph = Graph::<(), usize, Directed, usize>::from_edges(edges);
+    while graph.node_count() <= junctions.start.max(junctions.end) {
+        graph.add_node(());
+    }
+    let order = toposort(&graph, None).map_err(|_| eyre!("the slopes allow walking in circles"))?;
+    let mut longest = vec![None; graph.node_count()];
+    longest[junctions.start] = Some(0);
+    for node in order {
+        let Some(here) = longest[node.index()] else {
+            continue;
+        };
+        for edge in graph.edges(node) {
+            let there = &mut longest[edge.target().index()];
+            *there = Some(there.unwrap_or(0).max(here + edge.weight()));
+        }
+    }
+    longest[junctions.end].ok_or_eyre("the end cannot be reached")
+}
+
+/// Longest walk from the start to the end over any corridors, visiting no junction twice.
+///
+/// A depth first search over every simple path, with the visited junctions in a bitmask.
+fn longest_walk(junctions: &Junctions) -> Result<usize> {
+    let edges = junctions.corridors.iter().map(|&(from, to, steps, _)| (from, to, steps));
+    let graph = Graph::<(), usize, Directed, usize>::from_edges(edges);
+    if graph.node_count() > 64 {
+        return Err(eyre!("{} junctions is too many to search, at most 64", graph.node_count()));
+    }
+    let neighbours: Vec<Vec<(usize, usize)>> = graph
+        .node_indices()
+        .map(|n| graph.edges(n).map(|e| (e.target().index(), *e.weight())).collect())
+        .collect();
+    // Once at the only junction next to the end, turning away from the end can never reach it.
+    let (goal, last_leg) = match neighbours.get(junctions.end).map(Vec::as_slice) {
+        Some(&[(goal, steps)]) => (goal, steps),
+        _ => (junctions.end, 0),
+    };
+    fn search(neighbours: &[Vec<(usize, usize)>], node: usize, goal: usize, seen: u64) -> Option<usize> {
+        if node == goal {
+            return Some(0);
+        }
+        neighbours[node]
+            .iter()
+            .filter(|&&(next, _)| seen & (1 << next) == 0)
+            .filter_map(|&(next, steps)| search(neighbours, next, goal, seen | 1 << next).map(|rest| rest + steps))
+            .max()
+    }
+    if junctions.start >= neighbours.len() {
+        return Err(eyre!("the end cannot be reached"));
+    }
+    let longest = search(&neighbours, junctions.start, goal, 1 << junctions.start).ok_or_eyre("the end cannot be reached")?;
+    trace!("{} junctions, longest walk to the last one {longest}", neighbours.len());
+    Ok(longest + last_leg)
+}
+
+pub struct Walk {
+    junctions: Junctions,
+}
+
+impl<'a> Solution<'a> for Walk {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let map = grid::parse::<Tile>(fname, body)?;
+        let source = Source::new(fname, body);
+        let (rows, _) = map.dim();
+        let opening = |row: usize, what: &str| {
+            let mut open = map.row(row).indexed_iter().filter(|(_, &t)| t == Tile::Path).map(|(c, _)| [row, c]).collect::<Vec<_>>();
+            match open.len() {
+                1 => Ok(open.remove(0)),
+                n => Err(source.error_at(row + 1, 1, format!("expected one path tile in the {what} row, found {n}"))),
+            }
+        };
+        let start = opening(0, "top")?;
+        let end = opening(rows - 1, "bottom")?;
+        if start == end {
+            return Err(source.error_at(1, 1, "the map needs separate top and bottom rows"));
+        }
+        Ok(Walk { junctions: compress(&map, start, end) })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(longest_downhill(&self.junctions)? as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(longest_walk(&self.junctions)? as u64)
+    }
+}
@@ -0,0 +1 @@
+common::main!(walk::Walk);
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+/// Generates `size + 3` hailstones, all hit by one rock thrown from inside the puzzle's
+/// test area, so part 2 always has an answer.
+///
+/// Each hailstone is placed where it will be when the rock reaches it, at distinct times,
+/// with no velocity component shared with the rock's so their paths never run parallel.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let count = size.clamp(1, 300) + 3;
+    let position: [i64; 3] = [0; 3].map(|_| rng.gen_range(250_000_000_000_000..=350_000_000_000_000));
+    let velocity: [i64; 3] = [0; 3].map(|_| rng.gen_range(-200..=200));
+    let mut times = HashSet::new();
+    let mut body = String::new();
+    while times.len() < count {
+        let t: i64 = rng.gen_range(1_000_000_000..=100_000_000_000);
+        if !times.insert(t) {
+            continue;
+        }
+        let hail_velocity = velocity.map(|v| loop {
+            let h = rng.gen_range(-300..=300);
+            if h != v {
+                break h;
+            }
+        });
+        let at = [0, 1, 2].map(|i| position[i] + t * (velocity[i] - hail_velocity[i]));
+        let [px, py, pz] = at;
+        let [vx, vy, vz] = hail_velocity;
+        body.push_str(&format!("{px}, {py}, {pz} @ {vx}, {vy}, {vz}\n"));
+    }
+    body
+}
@@ -0,0 +1,173 @@
+pub mod generator;
+
+use std::ops::RangeInclusive;
+
+use common::diagnostic::Source;
+use common::options;
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, Result};
+use itertools::Itertools;
+use num::rational::Ratio;
+use num::{BigInt, BigRational, One, Signed, ToPrimitive, Zero};
+
+/// The test area of the puzzle, used unless the `area` option gives another as `MIN..=MAX`.
+const AREA: RangeInclusive<i64> = 200_000_000_000_000..=400_000_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3],
+}
+
+fn parse_area(s: &str) -> Result<RangeInclusive<i64>> {
+    let (min, max) = s.split_once("..=").ok_or_else(|| eyre!("expected MIN..=MAX"))?;
+    Ok(min.trim().parse()?..=max.trim().parse()?)
+}
+
+/// Whether the paths of two hailstones cross in the x, y plane inside `area`, at a point
+/// both reach in the future.
+///
+/// Solves `a.p + t a.v = b.p + s b.v` with Cramer's rule, keeping `t`, `s` and the crossing
+/// as exact fractions. Parallel paths never cross, even when they overlap.
+fn crosses(a: &Hailstone, b: &Hailstone, area: &RangeInclusive<i64>) -> bool {
+    let [pax, pay, _] = a.position.map(i128::from);
+    let [vax, vay, _] = a.velocity.map(i128::from);
+    let [pbx, pby, _] = b.position.map(i128::from);
+    let [vbx, vby, _] = b.velocity.map(i128::from);
+    let det = vbx * vay - vax * vby;
+    if det == 0 {
+        return false;
+    }
+    let t = Ratio::new(vbx * (pby - pay) - vby * (pbx - pax), det);
+    let s = Ratio::new(vax * (pby - pay) - vay * (pbx - pax), det);
+    if t.is_negative() || s.is_negative() {
+        return false;
+    }
+    let (min, max) = (Ratio::from(i128::from(*area.start())), Ratio::from(i128::from(*area.end())));
+    let x = Ratio::from(pax) + t * vax;
+    let y = Ratio::from(pay) + t * vay;
+    (min..=max).contains(&x) && (min..=max).contains(&y)
+}
+
+/// Solves a square linear system exactly by Gaussian elimination, or `None` if it is singular.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col && !row[col].is_zero() {
+                let factor = &row[col] / &pivot_row[col];
+                for (x, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *x -= &factor * p;
+                }
+            }
+        }
+    }
+    Some((0..n).map(|r| &rows[r][n] / &rows[r][r]).collect())
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn sub(a: [i64; 3], b: [i64; 3]) -> [i128; 3] {
+    [0, 1, 2].map(|i| i128::from(a[i]) - i128::from(b[i]))
+}
+
+/// The three equations `p × (vj - vi) + (pj - pi) × v = pj × vj - pi × vi` in the rock's
+/// position `p` and velocity `v`, as rows of the augmented matrix.
+///
+/// Each hailstone `i` is hit when `(p - pi) × (v - vi) = 0`. That is quadratic in the
+/// unknowns through `p × v`, which is the same for every hailstone and cancels between two.
+fn pair_equations(i: &Hailstone, j: &Hailstone) -> [[i128; 7]; 3] {
+    let w = sub(j.velocity, i.velocity);
+    let d = sub(j.position, i.position);
+    let wide = |v: [i64; 3]| v.map(i128::from);
+    let [c0, c1, c2] = [0, 1, 2].map(|k| cross(wide(j.position), wide(j.velocity))[k] - cross(wide(i.position), wide(i.velocity))[k]);
+    [
+        [0, w[2], -w[1], 0, -d[2], d[1], c0],
+        [-w[2], 0, w[0], d[2], 0, -d[0], c1],
+        [w[1], -w[0], 0, -d[1], d[0], 0, c2],
+    ]
+}
+
+/// Whether a rock thrown from `position` with `velocity` hits `h` at a time not in the past.
+fn hits(position: [i64; 3], velocity: [i64; 3], h: &Hailstone) -> bool {
+    let gap = sub(h.position, position);
+    let closing = sub(velocity, h.velocity);
+    if cross(gap, closing) != [0, 0, 0] {
+        return false;
+    }
+    // Along the line, the gap must close rather than open, unless it is already closed.
+    (0..3).all(|k| gap[k] == 0 || (gap[k].signum() == closing[k].signum()))
+}
+
+pub struct Hail {
+    hailstones: Vec<Hailstone>,
+}
+
+impl Hail {
+    /// Counts the pairs of hailstones whose future paths cross inside `area`, in x and y only.
+    pub fn crossings(&self, area: &RangeInclusive<i64>) -> usize {
+        self.hailstones.iter().tuple_combinations().filter(|(a, b)| crosses(a, b, area)).count()
+    }
+
+    /// The position and velocity of the one throw that hits every hailstone.
+    fn throw(&self) -> Result<([i64; 3], [i64; 3])> {
+        // Any two pairs sharing a hailstone give six equations; try triples until they are independent.
+        for (a, b, c) in self.hailstones.iter().tuple_combinations().take(1000) {
+            let rows: Vec<Vec<BigRational>> = pair_equations(a, b)
+                .into_iter()
+                .chain(pair_equations(a, c))
+                .map(|row| row.iter().map(|&x| BigRational::from_integer(BigInt::from(x))).collect())
+                .collect();
+            let Some(solution) = solve_linear(rows) else {
+                continue;
+            };
+            let whole: Option<Vec<i64>> = solution.iter().map(|x| x.denom().is_one().then(|| x.numer().to_i64()).flatten()).collect();
+            let whole = whole.ok_or_else(|| eyre!("the throw does not start from whole coordinates: {solution:?}"))?;
+            let (position, velocity) = ([whole[0], whole[1], whole[2]], [whole[3], whole[4], whole[5]]);
+            trace!("throw from {position:?} at {velocity:?}");
+            return match self.hailstones.iter().find(|h| !hits(position, velocity, h)) {
+                Some(h) => Err(eyre!("no single throw hits every hailstone, the best misses {h:?}")),
+                None => Ok((position, velocity)),
+            };
+        }
+        Err(eyre!("the hailstones do not fix a single throw"))
+    }
+}
+
+impl<'a> Solution<'a> for Hail {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let hailstones = body
+            .lines()
+            .map(|line| {
+                let (p, v) = line.split_once('@').ok_or_else(|| source.error(line, "expected 'position @ velocity'"))?;
+                let triple = |s: &'a str| -> Result<[i64; 3]> {
+                    let values: Vec<i64> = s.split(',').map(|n| source.parse(n.trim())).collect::<Result<_>>()?;
+                    values.try_into().map_err(|_| source.error(s, "expected x, y, z"))
+                };
+                Ok(Hailstone { position: triple(p)?, velocity: triple(v)? })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Hail { hailstones })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let area = options::get("area").map(|a| parse_area(&a).map_err(|e| eyre!("invalid option area={a}: {e}"))).transpose()?;
+        Ok(self.crossings(&area.unwrap_or(AREA)) as u64)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let (position, _) = self.throw()?;
+        let sum: i64 = position.iter().sum();
+        u64::try_from(sum).map_err(|_| eyre!("the throw starts at {position:?}, whose coordinates sum below zero"))
+    }
+}
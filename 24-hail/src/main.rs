@@ -0,0 +1 @@
+common::main!(hail::Hail);
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a wiring diagram of two groups of `size + 8` components or so, joined by
+/// exactly three wires.
+///
+/// Each group is wired as a ring with every component also joined to the next two after
+/// its neighbour, so cutting it apart takes at least six wires and the three between the
+/// groups are the only smallest cut. Each wire is listed under one of its ends.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.clamp(1, 1000);
+    let mut names = HashSet::new();
+    while names.len() < 2 * size + 24 {
+        let name: String = (0..3).map(|_| rng.gen_range(b'a'..=b'z') as char).collect();
+        names.insert(name);
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort_unstable();
+    names.shuffle(rng);
+    let split = size + 8 + rng.gen_range(0..=size.min(8));
+    let groups = [&names[..split], &names[split..]];
+    let mut wires = Vec::new();
+    for group in groups {
+        for (i, name) in group.iter().enumerate() {
+            for step in 1..=3 {
+                wires.push((name, &group[(i + step) % group.len()]));
+            }
+        }
+    }
+    let mut bridged = HashSet::new();
+    while bridged.len() < 3 {
+        let pair = (groups[0].choose(rng).unwrap(), groups[1].choose(rng).unwrap());
+        if bridged.insert(pair) {
+            wires.push(pair);
+        }
+    }
+    let mut listed: Vec<(&String, Vec<&String>)> = Vec::new();
+    wires.shuffle(rng);
+    for (a, b) in wires {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        match listed.iter_mut().find(|(name, _)| *name == from) {
+            Some((_, others)) => others.push(to),
+            None => listed.push((from, vec![to])),
+        }
+    }
+    let mut body = String::new();
+    for (name, others) in listed {
+        let others: Vec<&str> = others.iter().map(|s| s.as_str()).collect();
+        body.push_str(&format!("{name}: {}\n", others.join(" ")));
+    }
+    body
+}
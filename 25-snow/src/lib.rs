@@ -0,0 +1,161 @@
+pub mod generator;
+
+use std::collections::{BinaryHeap, HashMap};
+
+use common::diagnostic::Source;
+use common::dump;
+use common::solution::Solution;
+use common::trace;
+use eyre::{eyre, Result};
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
+
+/// The wires the puzzle says must be cut to split the machine in two.
+const WIRES_TO_CUT: usize = 3;
+
+/// A minimum cut: the components on one side, and how many wires cross it.
+struct Cut {
+    side: Vec<usize>,
+    weight: usize,
+}
+
+/// Finds a minimum cut of a connected graph with the Stoer–Wagner algorithm.
+///
+/// Each phase adds nodes one at a time, always the one most strongly wired to those already
+/// added. The wires into the last node form a cut, the smallest between it and the one before,
+/// so merging those two loses nothing. Every node is merged away after `n - 1` phases, and the
+/// smallest cut seen is a minimum cut of the whole graph.
+fn min_cut(graph: &UnGraph<&str, (), usize>) -> Option<Cut> {
+    let n = graph.node_count();
+    let mut wires: Vec<HashMap<usize, usize>> = vec![HashMap::new(); n];
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        *wires[a].entry(b).or_default() += 1;
+        *wires[b].entry(a).or_default() += 1;
+    }
+    let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<Cut> = None;
+    while active.len() > 1 {
+        let mut added = vec![false; n];
+        let mut strength = vec![0; n];
+        let mut queue = BinaryHeap::<(usize, usize)>::new();
+        let (mut before, mut last) = (active[0], active[0]);
+        for _ in 0..active.len() {
+            // Stale entries are skipped; a node only cut off from the rest is added with strength 0.
+            let next = std::iter::from_fn(|| queue.pop())
+                .map(|(_, node)| node)
+                .find(|&node| !added[node])
+                .or_else(|| active.iter().copied().find(|&node| !added[node]))?;
+            added[next] = true;
+            (before, last) = (last, next);
+            for (&other, &count) in &wires[next] {
+                if !added[other] {
+                    strength[other] += count;
+                    queue.push((strength[other], other));
+                }
+            }
+        }
+        if best.as_ref().is_none_or(|b| strength[last] < b.weight) {
+            best = Some(Cut { side: members[last].clone(), weight: strength[last] });
+        }
+        // Merge the last node into the one before it.
+        let moved = std::mem::take(&mut members[last]);
+        members[before].extend(moved);
+        for (other, count) in std::mem::take(&mut wires[last]) {
+            wires[other].remove(&last);
+            if other != before {
+                *wires[before].entry(other).or_default() += count;
+                *wires[other].entry(before).or_default() += count;
+            }
+        }
+        active.retain(|&node| node != last);
+    }
+    best
+}
+
+/// The machine split in two by cutting wires.
+struct Split<'a> {
+    /// The number of components in each group.
+    groups: [usize; 2],
+    /// The wires cut, as the names of their ends in order.
+    wires: Vec<(&'a str, &'a str)>,
+}
+
+pub struct Snow<'a> {
+    graph: UnGraph<&'a str, (), usize>,
+}
+
+impl<'a> Snow<'a> {
+    /// Splits the machine in two by cutting as few wires as possible, which must be three.
+    /// With `--dump`, lists the wires cut and the size of each group.
+    fn split(&self) -> Result<Split<'a>> {
+        let graph = &self.graph;
+        let cut = min_cut(graph).ok_or_else(|| eyre!("there are no components to split"))?;
+        if cut.weight != WIRES_TO_CUT {
+            return Err(eyre!("the smallest cut has {} wires, not {WIRES_TO_CUT}", cut.weight));
+        }
+        let mut side = vec![false; graph.node_count()];
+        for &node in &cut.side {
+            side[node] = true;
+        }
+        let mut wires: Vec<_> = graph
+            .edge_references()
+            .filter(|e| side[e.source().index()] != side[e.target().index()])
+            .map(|e| {
+                let (a, b) = (graph[e.source()], graph[e.target()]);
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        wires.sort_unstable();
+        let groups = [cut.side.len(), graph.node_count() - cut.side.len()];
+        trace!("cut {wires:?} between groups of {groups:?}");
+        if dump::enabled() {
+            let listed: Vec<_> = wires.iter().map(|(a, b)| format!("{a}/{b}\n")).collect();
+            dump::section("cut", listed.concat());
+            dump::section("groups", format!("{} and {}\n", groups[0], groups[1]));
+        }
+        Ok(Split { groups, wires })
+    }
+}
+
+impl<'a> Solution<'a> for Snow<'a> {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self> {
+        let source = Source::new(fname, body);
+        let mut graph = UnGraph::default();
+        let mut nodes = HashMap::new();
+        let mut node = |graph: &mut UnGraph<&'a str, (), usize>, name: &'a str| -> Result<NodeIndex<usize>> {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(source.error(name, format!("invalid component name '{name}'")));
+            }
+            Ok(*nodes.entry(name).or_insert_with(|| graph.add_node(name)))
+        };
+        for line in body.lines() {
+            let (name, others) = line.split_once(':').ok_or_else(|| source.error(line, "expected 'name: other names'"))?;
+            let from = node(&mut graph, name)?;
+            for other in others.split_whitespace() {
+                let to = node(&mut graph, other)?;
+                if from == to {
+                    return Err(source.error(other, "a component cannot be wired to itself"));
+                }
+                graph.update_edge(from, to, ());
+            }
+        }
+        Ok(Snow { graph })
+    }
+
+    /// The product of the sizes of the two groups left by cutting three wires.
+    fn part1(&self) -> Result<u64> {
+        let [a, b] = self.split()?.groups;
+        Ok((a * b) as u64)
+    }
+
+    /// Day 25 has no second puzzle, so this is not a puzzle answer: it is the number of wires
+    /// cut, which is always three once part 1 succeeds.
+    fn part2(&self) -> Result<u64> {
+        Ok(self.split()?.wires.len() as u64)
+    }
+}
@@ -0,0 +1 @@
+common::main!(snow::Snow);
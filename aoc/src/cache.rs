@@ -0,0 +1,98 @@
+//! Puzzle inputs kept in a cache directory, downloaded on first use.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use eyre::{bail, eyre, Result, WrapErr};
+
+const YEAR: u32 = 2023;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Where inputs are cached and where missing ones are fetched from.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Cache {
+    /// Uses `dir`, or `$XDG_CACHE_HOME/aoc-2023` (`~/.cache/aoc-2023`) when not given.
+    ///
+    /// The session token is the `session` cookie of a logged in browser, taken from
+    /// `AOC_SESSION` or else a `session` file in the cache directory.
+    pub fn new(dir: Option<PathBuf>, base_url: &str) -> Result<Self> {
+        let dir = match dir {
+            Some(dir) => dir,
+            None => std::env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+                .ok_or_else(|| eyre!("no cache directory: set --cache-dir or AOC_CACHE_DIR"))?
+                .join(format!("aoc-{YEAR}")),
+        };
+        if let Some(day_dir) = day_crate(&dir) {
+            bail!("refusing to cache inputs in {}, inside day crate {}", dir.display(), day_dir.display());
+        }
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(token) => Some(token),
+            Err(_) => fs::read_to_string(dir.join("session")).ok(),
+        }
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty());
+        Ok(Cache { dir, base_url: base_url.trim_end_matches('/').to_string(), session })
+    }
+
+    pub fn path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    /// Returns the cached input for a day, downloading it only if it is not cached yet.
+    pub fn input(&self, day: usize) -> Result<PathBuf> {
+        let path = self.path(day);
+        if path.is_file() {
+            return Ok(path);
+        }
+        let body = self.fetch(day)?;
+        fs::create_dir_all(&self.dir).wrap_err_with(|| format!("cannot create {}", self.dir.display()))?;
+        // Write beside the final name and rename, so an interrupted download is never cached.
+        let partial = path.with_extension("part");
+        fs::write(&partial, body).wrap_err_with(|| format!("cannot write {}", partial.display()))?;
+        fs::rename(&partial, &path)?;
+        Ok(path)
+    }
+
+    fn fetch(&self, day: usize) -> Result<String> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            eyre!("day {day} is not cached and there is no session token: set AOC_SESSION or write {}", self.dir.join("session").display())
+        })?;
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", concat!("aoc-2023/", env!("CARGO_PKG_VERSION")))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => eyre!("fetching {url} failed with status {code}"),
+                e => eyre!(e).wrap_err(format!("cannot fetch {url}")),
+            })?;
+        let mut body = String::new();
+        response.into_reader().read_to_string(&mut body).wrap_err_with(|| format!("cannot read {url}"))?;
+        if body.is_empty() {
+            bail!("{url} returned an empty input");
+        }
+        Ok(body)
+    }
+}
+
+/// The day crate, a `NN-name` directory with a manifest, that contains `dir` if any.
+fn day_crate(dir: &Path) -> Option<PathBuf> {
+    let dir = std::path::absolute(dir).ok()?;
+    dir.ancestors()
+        .find(|d| {
+            let name = d.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let bytes = name.as_bytes();
+            bytes.len() > 3 && bytes[..2].iter().all(u8::is_ascii_digit) && bytes[2] == b'-' && d.join("Cargo.toml").is_file()
+        })
+        .map(Path::to_path_buf)
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use common::input;
+use common::options;
+use eyre::{bail, Result, WrapErr};
+use serde::Deserialize;
+
+use crate::days;
+
+/// An expected answer; numbers and strings are both accepted so the file reads naturally.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Case {
+    pub day: usize,
+    pub part: u8,
+    pub input: PathBuf,
+    answer: Answer,
+    /// Options of the day for this case, on top of any given on the command line.
+    #[serde(default)]
+    options: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "case")]
+    pub cases: Vec<Case>,
+}
+
+pub fn load(path: &Path) -> Result<Answers> {
+    let text = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("cannot read {}", path.display()))?;
+    let answers = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&text)?,
+        _ => toml::from_str(&text)?,
+    };
+    Ok(answers)
+}
+
+fn solve(case: &Case, input: &Path) -> Result<String> {
+    let parse = days::get(case.day)?;
+    let input = input::read(input)?;
+    let saved: Vec<_> = case.options.iter().map(|(key, value)| {
+        let old = options::get(key);
+        options::set(key, value);
+        (key, old)
+    }).collect();
+    let answer = parse(&input.name, &input.body).and_then(|solution| solution.part(case.part));
+    for (key, old) in saved {
+        match old {
+            Some(old) => options::set(key, &old),
+            None => {
+                options::remove(key);
+            }
+        }
+    }
+    Ok(answer?.to_string())
+}
+
+/// Runs every case in the answers file, whose input paths are relative to the file itself.
+pub fn check(path: &Path) -> Result<()> {
+    let answers = load(path).wrap_err_with(|| format!("invalid answers file {}", path.display()))?;
+    let base = path.parent().unwrap_or(Path::new("."));
+    let mut failed = 0;
+    for case in answers.cases.iter() {
+        let input = base.join(&case.input);
+        let label = format!("day {:2} part {} {}", case.day, case.part, case.input.display());
+        match solve(case, &input) {
+            Ok(answer) if answer == case.answer.to_string() => println!("ok    {label}"),
+            Ok(answer) => {
+                failed += 1;
+                println!("FAIL  {label}: expected {} but got {answer}", case.answer);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("ERROR {label}: {e:#}");
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {} checks failed", answers.cases.len());
+    }
+    println!("all {} checks passed", answers.cases.len());
+    Ok(())
+}
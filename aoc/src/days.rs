@@ -0,0 +1,90 @@
+use common::solution::{self, ParseFn, StreamFn};
+use eyre::{eyre, Result};
+use rand::rngs::StdRng;
+
+macro_rules! day {
+    ($solution:ty) => {
+        |fname, body| solution::parse::<$solution>(fname, body)
+    };
+}
+
+pub const DAYS: [ParseFn; 25] = [
+    day!(trebuchet::Trebuchet),
+    day!(cube::Cube),
+    day!(gears::Gears),
+    day!(scratchcards::Scratchcards),
+    day!(soilmap::Soilmap),
+    day!(race::Race),
+    day!(camel::Camel),
+    day!(wasteland::Wasteland),
+    day!(mirage::Mirage),
+    day!(pipemaze::Pipemaze),
+    day!(galaxies::Galaxies),
+    day!(hotsprings::Hotsprings),
+    day!(mirrors::Mirrors),
+    day!(rocks::Rocks),
+    day!(lens::Lens),
+    day!(lava::Lava),
+    day!(crucible::City),
+    day!(lagoon::Lagoon),
+    day!(aplenty::Aplenty),
+    day!(pulse::Pulse),
+    day!(steps::Steps),
+    day!(slabs::Slabs),
+    day!(walk::Walk),
+    day!(hail::Hail),
+    day!(snow::Snow),
+];
+
+/// Writes a random puzzle input of roughly the given size.
+pub type GenerateFn = fn(&mut StdRng, usize) -> String;
+
+pub const GENERATORS: [GenerateFn; 25] = [
+    trebuchet::generator::input,
+    cube::generator::input,
+    gears::generator::input,
+    scratchcards::generator::input,
+    soilmap::generator::input,
+    race::generator::input,
+    camel::generator::input,
+    wasteland::generator::input,
+    mirage::generator::input,
+    pipemaze::generator::input,
+    galaxies::generator::input,
+    hotsprings::generator::input,
+    mirrors::generator::input,
+    rocks::generator::input,
+    lens::generator::input,
+    lava::generator::input,
+    crucible::generator::input,
+    lagoon::generator::input,
+    aplenty::generator::input,
+    pulse::generator::input,
+    steps::generator::input,
+    slabs::generator::input,
+    walk::generator::input,
+    hail::generator::input,
+    snow::generator::input,
+];
+
+pub fn get(day: usize) -> Result<ParseFn> {
+    day.checked_sub(1)
+        .and_then(|i| DAYS.get(i))
+        .copied()
+        .ok_or_else(|| eyre!("no solution for day {day}"))
+}
+
+/// The streaming entry point of a day that reads its input a line at a time, if it has one.
+pub fn stream(day: usize) -> Option<StreamFn> {
+    match day {
+        1 => Some(trebuchet::stream),
+        _ => None,
+    }
+}
+
+pub fn generator(day: usize) -> Result<GenerateFn> {
+    day.checked_sub(1)
+        .and_then(|i| GENERATORS.get(i))
+        .copied()
+        .ok_or_else(|| eyre!("no generator for day {day}"))
+}
@@ -0,0 +1,233 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use common::dot;
+use common::dump;
+use common::image;
+use common::input;
+use common::options;
+use common::render;
+use common::solution::{Answer, ParseFn};
+use common::trace;
+use eyre::Result;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Serialize;
+
+mod cache;
+mod check;
+mod days;
+mod scaffold;
+mod timing;
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs Advent of Code 2023 solutions")]
+struct Cli {
+    /// Print solver diagnostics to stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+    /// Draw the grids of days that support it to stderr
+    #[arg(long, global = true)]
+    visualize: bool,
+    /// Print other intermediate state of days that support it to stderr
+    #[arg(long, global = true)]
+    dump: bool,
+    /// Save the final grid of days that support it as a PNG or PPM file
+    #[arg(long, global = true, value_name = "FILE")]
+    image: Option<PathBuf>,
+    /// Save a PNG per step of days that animate, such as day 14's spin cycles
+    #[arg(long, global = true, value_name = "DIR")]
+    frames: Option<PathBuf>,
+    /// Save the graph of days that support it as a Graphviz DOT file
+    #[arg(long, global = true, value_name = "FILE")]
+    dot: Option<PathBuf>,
+    /// Set an option of the day, such as `--opt area=7..=27` for day 24
+    #[arg(long = "opt", global = true, value_name = "KEY=VALUE", value_parser = options::parse_setting)]
+    opts: Vec<(String, String)>,
+    /// Directory puzzle inputs are cached in [default: ~/.cache/aoc-2023]
+    #[arg(long, global = true, env = "AOC_CACHE_DIR", value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+    /// Site missing inputs are downloaded from, with the session token in AOC_SESSION
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = cache::DEFAULT_BASE_URL)]
+    base_url: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day's puzzle for each input file, `-` for stdin and `.gz` decompressed
+    ///
+    /// Without input files the day's own input is used from the cache, and downloaded
+    /// into it the first time.
+    Run {
+        day: usize,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        inputs: Vec<PathBuf>,
+    },
+    /// Compare answers against a file of expected results (TOML or JSON)
+    Check {
+        #[arg(default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Print a random input for a day
+    Gen {
+        day: usize,
+        /// Seed for the random generator, so inputs can be reproduced
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Rough scale of the input, such as its number of lines or grid width
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Create the crate for a new day, named NN-NAME, and add it to the workspace
+    New {
+        day: usize,
+        name: String,
+    },
+    /// Time parsing and each part separately over repeated runs
+    Time {
+        /// Number of runs per input
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        repeat: u64,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Answers file whose inputs are timed when no targets are given
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Inputs to time, as DAY=INPUT
+        #[arg(value_parser = timing::parse_target)]
+        targets: Vec<timing::Target>,
+    },
+}
+
+/// One solved part, as printed by `run --format json`.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: usize,
+    input: &'a str,
+    part: u8,
+    answer: serde_json::Value,
+    /// Seconds spent solving the part, excluding parsing. Days that stream their input solve
+    /// every part in one pass, and each part reports the time of the whole pass
+    elapsed: f64,
+}
+
+fn run(day: usize, parse: ParseFn, part: Option<u8>, format: Format, inputs: &[PathBuf]) -> Result<()> {
+    let parts: Vec<u8> = part.map_or(1..=2, |p| p..=p).collect();
+    let stream = days::stream(day);
+    input::for_each_reader(inputs, |name, reader| {
+        if inputs.len() > 1 && matches!(format, Format::Text) {
+            println!("==> {name} <==");
+        }
+        match stream {
+            // A streaming day solves every part in the same pass, which is timed as a whole.
+            Some(stream) => {
+                let start = Instant::now();
+                let answers = stream(name, reader, &parts)?;
+                let elapsed = start.elapsed().as_secs_f64();
+                for (&p, answer) in parts.iter().zip(answers) {
+                    print_answer(format, day, name, p, answer?, elapsed)?;
+                }
+            }
+            None => {
+                let input = input::read_all(name.to_string(), reader)?;
+                let solution = parse(&input.name, &input.body)?;
+                solution.export()?;
+                for &p in &parts {
+                    let start = Instant::now();
+                    let answer = solution.part(p)?;
+                    print_answer(format, day, name, p, answer, start.elapsed().as_secs_f64())?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+fn print_answer(format: Format, day: usize, input: &str, part: u8, answer: Answer, elapsed: f64) -> Result<()> {
+    match format {
+        Format::Text => println!("{answer}"),
+        Format::Json => {
+            let answer = match answer {
+                Answer::Unsigned(n) => n.into(),
+                Answer::Signed(n) => n.into(),
+            };
+            let record = Record { day, input, part, answer, elapsed };
+            println!("{}", serde_json::to_string(&record)?);
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    trace::init_from_env();
+    if cli.verbose {
+        trace::set_enabled(true);
+    }
+    render::init_from_env();
+    if cli.visualize {
+        render::set_enabled(true);
+    }
+    dump::init_from_env();
+    if cli.dump {
+        dump::set_enabled(true);
+    }
+    image::init_from_env();
+    if cli.image.is_some() {
+        image::set_output(cli.image);
+    }
+    if cli.frames.is_some() {
+        image::set_frames(cli.frames);
+    }
+    dot::init_from_env();
+    if cli.dot.is_some() {
+        dot::set_output(cli.dot);
+    }
+    options::init_from_env()?;
+    for (key, value) in &cli.opts {
+        options::set(key, value);
+    }
+    match cli.command {
+        Command::Run { day, part, format, mut inputs } => {
+            let parse = days::get(day)?;
+            if inputs.is_empty() {
+                inputs.push(cache::Cache::new(cli.cache_dir, &cli.base_url)?.input(day)?);
+            }
+            run(day, parse, part, format, &inputs)
+        }
+        Command::Check { answers } => check::check(&answers),
+        Command::Gen { day, seed, size } => {
+            let generate = days::generator(day)?;
+            print!("{}", generate(&mut StdRng::seed_from_u64(seed), size));
+            Ok(())
+        }
+        Command::New { day, name } => {
+            let root = scaffold::workspace_root(&std::env::current_dir()?)?;
+            let dir = scaffold::new_day(&root, day, &name)?;
+            println!("created {}", dir.display());
+            eprintln!("register it in aoc/src/days.rs and aoc/Cargo.toml to run it with `aoc run`");
+            Ok(())
+        }
+        Command::Time { repeat, format, answers, targets } => {
+            let targets = if targets.is_empty() {
+                timing::targets_from_answers(&answers)?
+            } else {
+                targets
+            };
+            timing::time(&targets, repeat as usize, format)
+        }
+    }
+}
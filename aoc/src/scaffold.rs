@@ -0,0 +1,148 @@
+//! `aoc new`: creates the crate for a new day and adds it to the workspace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use eyre::{bail, eyre, Result, WrapErr};
+
+const MANIFEST: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+eyre = "0.6.11"
+rand = "0.8.5"
+"#;
+
+const MAIN: &str = "common::main!({name}::{Type});\n";
+
+const LIB: &str = r#"pub mod generator;
+
+use common::solution::Solution;
+use eyre::Result;
+
+pub struct {Type};
+
+impl<'a> Solution<'a> for {Type} {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(_fname: &'a str, _body: &'a str) -> Result<Self> {
+        Ok({Type})
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(0)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(0)
+    }
+}
+"#;
+
+const GENERATOR: &str = r#"use rand::Rng;
+
+/// Generates a random input of roughly `size` lines for `aoc gen`.
+pub fn input<R: Rng + ?Sized>(_rng: &mut R, _size: usize) -> String {
+    String::new()
+}
+"#;
+
+const TEST: &str = r#"//! Answers for the example in the puzzle text.
+
+use common::solution::Solution;
+use eyre::Result;
+use {name}::{Type};
+
+/// The example's answers to parts 1 and 2.
+const ANSWERS: (u64, u64) = (0, 0);
+
+#[test]
+fn example() -> Result<()> {
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt"))?;
+    let solution = {Type}::parse("example.txt", &body)?;
+    assert_eq!((solution.part1()?, solution.part2()?), ANSWERS);
+    Ok(())
+}
+"#;
+
+/// Finds the workspace root: the closest directory at or above `dir` whose manifest has `[workspace]`.
+pub fn workspace_root(dir: &Path) -> Result<PathBuf> {
+    dir.ancestors()
+        .find(|d| fs::read_to_string(d.join("Cargo.toml")).is_ok_and(|m| m.lines().any(|l| l.trim() == "[workspace]")))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| eyre!("no workspace Cargo.toml above {}", dir.display()))
+}
+
+/// Creates `NN-name/` under `root` with a `Solution` stub, an input generator stub, a binary,
+/// an example test and an empty `example.txt`, then lists it in the workspace members.
+pub fn new_day(root: &Path, day: usize, name: &str) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        bail!("day {day} is not between 1 and 25");
+    }
+    let mut chars = name.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        || !chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        bail!("'{name}' is not a valid crate name: use lowercase letters, digits and '_'");
+    }
+    let prefix = format!("{day:02}-");
+    for entry in fs::read_dir(root).wrap_err_with(|| format!("cannot read {}", root.display()))? {
+        let existing = entry?.path();
+        if existing.file_name().is_some_and(|f| f.to_string_lossy().starts_with(&prefix)) {
+            bail!("day {day} already exists in {}", existing.display());
+        }
+    }
+    let dir_name = format!("{prefix}{name}");
+    let dir = root.join(&dir_name);
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).wrap_err_with(|| format!("cannot read {}", manifest_path.display()))?;
+    let manifest = add_member(&manifest, &dir_name)?;
+
+    let type_name: String = name
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_ascii_uppercase()).into_iter().chain(chars)
+        })
+        .collect();
+    let fill = |template: &str| template.replace("{name}", name).replace("{Type}", &type_name);
+    for (path, contents) in [
+        ("Cargo.toml", fill(MANIFEST)),
+        ("src/main.rs", fill(MAIN)),
+        ("src/lib.rs", fill(LIB)),
+        ("src/generator.rs", GENERATOR.to_string()),
+        ("tests/example.rs", fill(TEST)),
+        ("example.txt", String::new()),
+    ] {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap_or(&dir))?;
+        fs::write(&path, contents).wrap_err_with(|| format!("cannot write {}", path.display()))?;
+    }
+    fs::write(&manifest_path, manifest).wrap_err_with(|| format!("cannot write {}", manifest_path.display()))?;
+    Ok(dir)
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the list sorted.
+fn add_member(manifest: &str, member: &str) -> Result<String> {
+    let start = manifest.find("members = [").ok_or_else(|| eyre!("workspace has no members list"))?;
+    let open = start + "members = [".len();
+    let close = open + manifest[open..].find(']').ok_or_else(|| eyre!("unterminated members list"))?;
+    let mut members: Vec<_> = manifest[open..close]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        bail!("{member} is already a workspace member");
+    }
+    members.push(member);
+    members.sort_unstable();
+    let list: String = members.iter().map(|m| format!("    \"{m}\",\n")).collect();
+    Ok(format!("{}\n{list}{}", &manifest[..open], &manifest[close..]))
+}
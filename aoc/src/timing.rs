@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::input;
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+
+use crate::{check, days, Format};
+
+/// A day and input file to time, with the parts to solve for it.
+#[derive(Debug, Clone)]
+pub struct Target {
+    day: usize,
+    input: PathBuf,
+    parts: Vec<u8>,
+}
+
+/// Parses a `DAY=INPUT` command line argument.
+pub fn parse_target(s: &str) -> Result<Target, String> {
+    let (day, input) = s.split_once('=').ok_or("expected DAY=INPUT")?;
+    let day = day.parse().map_err(|e| format!("invalid day '{day}': {e}"))?;
+    Ok(Target {
+        day,
+        input: input.into(),
+        parts: vec![1, 2],
+    })
+}
+
+/// Collects the distinct inputs of an answers file, keeping only the parts it checks.
+pub fn targets_from_answers(path: &Path) -> Result<Vec<Target>> {
+    let answers = check::load(path).wrap_err_with(|| format!("invalid answers file {}", path.display()))?;
+    let base = path.parent().unwrap_or(Path::new("."));
+    let mut targets: BTreeMap<(usize, PathBuf), Vec<u8>> = BTreeMap::new();
+    for case in answers.cases {
+        targets.entry((case.day, base.join(&case.input))).or_default().push(case.part);
+    }
+    Ok(targets
+        .into_iter()
+        .map(|((day, input), mut parts)| {
+            parts.sort();
+            parts.dedup();
+            Target { day, input, parts }
+        })
+        .collect())
+}
+
+#[derive(Debug, Serialize)]
+struct Timing {
+    day: usize,
+    input: String,
+    stage: &'static str,
+    repeat: usize,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+}
+
+impl Timing {
+    fn new(target: &Target, stage: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        Timing {
+            day: target.day,
+            input: target.input.display().to_string(),
+            stage,
+            repeat: samples.len(),
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// Parses and solves a target `repeat` times, timing every stage separately.
+fn measure(target: &Target, repeat: usize) -> Result<Vec<Timing>> {
+    let parse = days::get(target.day)?;
+    let input = input::read(&target.input)?;
+    let mut parse_times = Vec::with_capacity(repeat);
+    let mut part_times = vec![Vec::with_capacity(repeat); target.parts.len()];
+    for _ in 0..repeat {
+        let start = Instant::now();
+        let solution = parse(&input.name, &input.body)?;
+        parse_times.push(start.elapsed());
+        for (&part, times) in target.parts.iter().zip(part_times.iter_mut()) {
+            let start = Instant::now();
+            solution.part(part)?;
+            times.push(start.elapsed());
+        }
+    }
+    let mut timings = vec![Timing::new(target, "parse", parse_times)];
+    for (&part, times) in target.parts.iter().zip(part_times) {
+        let stage = if part == 1 { "part1" } else { "part2" };
+        timings.push(Timing::new(target, stage, times));
+    }
+    Ok(timings)
+}
+
+pub fn time(targets: &[Target], repeat: usize, format: Format) -> Result<()> {
+    let mut timings = Vec::new();
+    for target in targets {
+        let measured = measure(target, repeat)
+            .wrap_err_with(|| format!("day {} {}", target.day, target.input.display()))?;
+        timings.extend(measured);
+    }
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&timings)?),
+        Format::Text => {
+            let width = timings.iter().map(|t| t.input.len()).max().unwrap_or(0);
+            println!("{:>3}  {:width$}  {:5}  {:>12}  {:>12}  {:>12}", "day", "input", "stage", "min", "median", "max");
+            for t in timings.iter() {
+                let show = |ns| format!("{:.2?}", Duration::from_nanos(ns));
+                println!(
+                    "{:>3}  {:width$}  {:5}  {:>12}  {:>12}  {:>12}",
+                    t.day,
+                    t.input,
+                    t.stage,
+                    show(t.min_ns),
+                    show(t.median_ns),
+                    show(t.max_ns)
+                );
+            }
+        }
+    }
+    Ok(())
+}
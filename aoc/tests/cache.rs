@@ -0,0 +1,104 @@
+//! `aoc run DAY` without inputs reads the day's input from the cache, downloading it
+//! from a mock of the puzzle site on a miss.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Serves `body` with `status` to every request, recording each request's head.
+fn serve(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            seen.lock().unwrap().push(head);
+            write!(stream, "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+        }
+    });
+    (url, requests)
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cache").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn aoc(args: &[&str], cache: &Path, url: &str, session: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.args(args).env("AOC_CACHE_DIR", cache).env("AOC_BASE_URL", url).env_remove("AOC_SESSION");
+    if let Some(session) = session {
+        command.env("AOC_SESSION", session);
+    }
+    command.output().unwrap()
+}
+
+const RACE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+#[test]
+fn miss_downloads_once_then_hits() {
+    let (url, requests) = serve(200, RACE);
+    let cache = cache_dir("hit");
+    let first = aoc(&["run", "6"], &cache, &url, Some("secret"));
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stderr));
+    assert_eq!(String::from_utf8_lossy(&first.stdout), "288\n71503\n");
+    assert_eq!(fs::read_to_string(cache.join("day06.txt")).unwrap(), RACE);
+    {
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/6/input "), "{}", requests[0]);
+        assert!(requests[0].to_ascii_lowercase().contains("cookie: session=secret"), "{}", requests[0]);
+    }
+
+    let second = aoc(&["run", "6"], &cache, &url, None);
+    assert!(second.status.success(), "{}", String::from_utf8_lossy(&second.stderr));
+    assert_eq!(first.stdout, second.stdout);
+    assert_eq!(requests.lock().unwrap().len(), 1, "cached input was downloaded again");
+}
+
+#[test]
+fn miss_without_session_is_an_error() {
+    let (url, requests) = serve(200, RACE);
+    let cache = cache_dir("no-session");
+    let output = aoc(&["run", "6"], &cache, &url, None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_SESSION"));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn failed_download_is_not_cached() {
+    let (url, _) = serve(404, "Please don't repeatedly request this endpoint before it unlocks!");
+    let cache = cache_dir("not-found");
+    let output = aoc(&["run", "6"], &cache, &url, Some("secret"));
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("status 404"));
+    assert!(!cache.join("day06.txt").exists());
+}
+
+#[test]
+fn cache_inside_a_day_crate_is_refused() {
+    let (url, requests) = serve(200, RACE);
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let output = aoc(&["run", "6"], &root.join("06-race").join("inputs"), &url, Some("secret"));
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("refusing to cache inputs"));
+    assert!(requests.lock().unwrap().is_empty());
+    assert!(!root.join("06-race").join("inputs").exists());
+}
@@ -0,0 +1,38 @@
+//! Graphs saved as Graphviz DOT files.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn day20_writes_the_module_graph() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("pulse.dot");
+    let _ = fs::remove_file(&path);
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--part", "1", "--dot"])
+        .arg(&path)
+        .arg("20")
+        .arg(root.join("20-pulse/example2.txt"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let dot = fs::read_to_string(&path).unwrap();
+    assert!(dot.starts_with("digraph {"), "{dot}");
+    for node in ["broadcaster", "%a", "&inv", "&con", "output"] {
+        assert!(dot.contains(&format!("label = \"{node}\"")), "missing {node} in {dot}");
+    }
+    assert!(dot.contains("shape = box") && dot.contains("shape = diamond"), "{dot}");
+    assert_eq!(dot.matches("->").count(), 6, "{dot}");
+}
+
+#[test]
+fn day20_writes_the_graph_whichever_part_runs() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let (input, path) = (dir.join("pulse-rx.txt"), dir.join("pulse-rx.dot"));
+    fs::write(&input, "broadcaster -> a\n%a -> con\n&con -> rx\n").unwrap();
+    let _ = fs::remove_file(&path);
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "--part", "2", "--dot"]).arg(&path).arg("20").arg(&input).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(&path).unwrap().contains("label = \"&con\""));
+}
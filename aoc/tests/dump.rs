@@ -0,0 +1,17 @@
+//! `--dump` prints a day's intermediate state to stderr, whichever part runs.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn dump_prints_the_cut_wires_and_group_sizes() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let input = root.join("25-snow/example.txt");
+    for part in ["1", "2"] {
+        let dumped = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "--dump", "--part", part, "25"]).arg(&input).output().unwrap();
+        assert!(dumped.status.success(), "{}", String::from_utf8_lossy(&dumped.stderr));
+        let stderr = String::from_utf8_lossy(&dumped.stderr);
+        assert!(stderr.contains("cut\nbvb/cmg\nhfx/pzl\njqt/nvd\n"), "part {part}: {stderr}");
+        assert!(stderr.contains("groups\n9 and 6\n"), "part {part}: {stderr}");
+    }
+}
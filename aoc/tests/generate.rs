@@ -0,0 +1,47 @@
+//! Every generated input must parse and solve without errors.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn aoc(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn generate(day: usize, seed: u64, size: usize) -> Vec<u8> {
+    let output = aoc(&["gen", &day.to_string(), "--seed", &seed.to_string(), "--size", &size.to_string()], b"");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    output.stdout
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for day in 1..=25 {
+        for seed in 0..3 {
+            for size in [1, 4, 12] {
+                let input = generate(day, seed, size);
+                let output = aoc(&["run", &day.to_string(), "-"], &input);
+                assert!(
+                    output.status.success(),
+                    "day {day} seed {seed} size {size} failed:\n{}\n{}",
+                    String::from_utf8_lossy(&input),
+                    String::from_utf8_lossy(&output.stderr)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn seeds_are_reproducible() {
+    for day in 1..=25 {
+        assert_eq!(generate(day, 42, 6), generate(day, 42, 6), "day {day}");
+    }
+}
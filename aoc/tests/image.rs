@@ -0,0 +1,75 @@
+//! Grids saved as images, in the format the file extension asks for.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn scratch(name: &str) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("image").join(name);
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    path
+}
+
+fn run(day: usize, dir: &str, flags: &[&Path]) -> std::process::Output {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(flags)
+        .args(["run", &day.to_string()])
+        .arg(root.join(dir).join("example.txt"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "day {day}: {}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+#[test]
+fn images_follow_the_extension() {
+    for (day, dir) in [(14, "14-rocks"), (16, "16-lava"), (18, "18-lagoon")] {
+        let png = scratch(&format!("day{day}.png"));
+        run(day, dir, &[Path::new("--image"), &png]);
+        assert!(fs::read(&png).unwrap().starts_with(b"\x89PNG\r\n\x1a\n"), "day {day}");
+
+        let ppm = scratch(&format!("day{day}.ppm"));
+        run(day, dir, &[Path::new("--image"), &ppm]);
+        let bytes = fs::read(&ppm).unwrap();
+        let header = String::from_utf8_lossy(&bytes[..bytes.len().min(20)]).into_owned();
+        let mut fields = header.split_ascii_whitespace();
+        assert_eq!(fields.next(), Some("P6"), "day {day}");
+        let width: usize = fields.next().unwrap().parse().unwrap();
+        let height: usize = fields.next().unwrap().parse().unwrap();
+        assert_eq!(fields.next(), Some("255"), "day {day}");
+        let pixels = bytes.len() - format!("P6\n{width} {height}\n255\n").len();
+        assert_eq!(pixels, width * height * 3, "day {day}");
+    }
+}
+
+#[test]
+fn unknown_extension_is_an_error() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("out.gif");
+    let _ = fs::remove_file(&path);
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("--image")
+        .arg(&path)
+        .args(["run", "14"])
+        .arg(root.join("14-rocks/example.txt"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("use a .png or .ppm file"));
+    assert!(!path.exists(), "{} was created", path.display());
+}
+
+#[test]
+fn day14_writes_a_frame_per_cycle() {
+    let frames = scratch("frames");
+    run(14, "14-rocks", &[Path::new("--frames"), &frames]);
+    let mut names: Vec<_> = fs::read_dir(&frames).unwrap().map(|e| e.unwrap().file_name().into_string().unwrap()).collect();
+    names.sort();
+    assert!(names.len() > 2, "{names:?}");
+    for (i, name) in names.iter().enumerate() {
+        assert_eq!(*name, format!("frame-{i:05}.png"));
+    }
+}
@@ -0,0 +1,87 @@
+//! Reading inputs from stdin, gzip files and several files in one run.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+fn example() -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../02-cube/example.txt").display().to_string()
+}
+
+fn aoc(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut pipe = child.stdin.take().unwrap();
+    if let Some(bytes) = stdin {
+        pipe.write_all(bytes).unwrap();
+    }
+    drop(pipe);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn reads_stdin() {
+    let body = fs::read(example()).unwrap();
+    let output = aoc(&["run", "2", "-"], Some(&body));
+    assert_eq!(stdout(&output), "8\n2286\n");
+}
+
+#[test]
+fn decompresses_gzip() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cube.txt.gz");
+    let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+    encoder.write_all(&fs::read(example()).unwrap()).unwrap();
+    encoder.finish().unwrap();
+    let output = aoc(&["run", "2", path.to_str().unwrap()], None);
+    assert_eq!(stdout(&output), "8\n2286\n");
+}
+
+#[test]
+fn answers_each_file() {
+    let example = example();
+    let output = aoc(&["run", "2", "--part", "1", &example, &example], None);
+    assert_eq!(stdout(&output), format!("==> {example} <==\n8\n==> {example} <==\n8\n"));
+}
+
+#[test]
+fn keeps_going_after_a_bad_file() {
+    let example = example();
+    let output = aoc(&["run", "2", "--part", "1", "missing.txt", &example], None);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("==> {example} <==\n8\n"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot read missing.txt"), "{stderr}");
+    assert!(stderr.contains("1 of 2 inputs failed"), "{stderr}");
+}
+
+#[test]
+fn day1_streams_gzip_and_keeps_going() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let path = dir.join("trebuchet.txt.gz");
+    let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+    encoder.write_all(b"two1nine\nabcone2threexyz\n").unwrap();
+    encoder.finish().unwrap();
+    let bad = dir.join("trebuchet-none.txt");
+    fs::write(&bad, "1\nnothing\n").unwrap();
+    let output = aoc(&["run", "1", bad.to_str().unwrap(), path.to_str().unwrap()], None);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.ends_with(&format!("==> {} <==\n{}\n{}\n", path.display(), 11 + 22, 29 + 13)), "{stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("trebuchet-none.txt:2:1: number not found"), "{stderr}");
+    assert!(stderr.contains("1 of 2 inputs failed"), "{stderr}");
+}
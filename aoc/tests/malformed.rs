@@ -0,0 +1,126 @@
+//! Feeds corrupted puzzle examples to every day and checks that the runner reports an
+//! answer or an error, but never panics or hangs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn examples(day: usize) -> Vec<PathBuf> {
+    let prefix = format!("{day:02}-");
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(root()).unwrap() {
+        let dir = entry.unwrap().path();
+        if !dir.file_name().unwrap().to_string_lossy().starts_with(&prefix) {
+            continue;
+        }
+        for file in fs::read_dir(&dir).unwrap() {
+            let file = file.unwrap().path();
+            let name = file.file_name().unwrap().to_string_lossy().into_owned();
+            if name.starts_with("example") && name.ends_with(".txt") {
+                paths.push(file);
+            }
+        }
+    }
+    paths.sort();
+    paths
+}
+
+/// Index of the first non-newline character at or after `fraction` of the body.
+fn position(body: &str, fraction: f64) -> Option<usize> {
+    let target = (body.len() as f64 * fraction) as usize;
+    body.char_indices().find(|&(i, c)| i >= target && c != '\n').map(|(i, _)| i)
+}
+
+fn replace(body: &str, fraction: f64, with: &str) -> Option<String> {
+    let i = position(body, fraction)?;
+    let len = body[i..].chars().next()?.len_utf8();
+    Some(format!("{}{with}{}", &body[..i], &body[i + len..]))
+}
+
+fn mutations(body: &str) -> Vec<String> {
+    let lines: Vec<_> = body.lines().collect();
+    let mut inputs = vec![
+        String::new(),
+        "\n".to_string(),
+        "this is not a puzzle input\n".to_string(),
+        body[..body.len() / 2].to_string(),
+        lines[1..].join("\n"),
+        lines[..lines.len() - 1].join("\n"),
+        format!("{}.\n{}", lines[0], lines[1..].join("\n")),
+    ];
+    for fraction in [0.25, 0.5, 0.75] {
+        inputs.extend(replace(body, fraction, "x"));
+        inputs.extend(replace(body, fraction, "#"));
+        inputs.extend(replace(body, fraction, ""));
+    }
+    inputs.extend(replace(body, 0.5, "9"));
+    inputs
+}
+
+/// Runs one part and returns its stderr, failing on panics and timeouts.
+fn run(day: usize, part: u8, input: &Path) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--part", &part.to_string(), &day.to_string()])
+        .arg(input)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if start.elapsed() > TIMEOUT {
+            child.kill().unwrap();
+            panic!("day {day} part {part} timed out on {}", input.display());
+        }
+        thread::sleep(Duration::from_millis(5));
+    };
+    let stderr = std::io::read_to_string(child.stderr.take().unwrap()).unwrap();
+    assert!(
+        status.code() != Some(101) && !stderr.contains("panicked"),
+        "day {day} part {part} panicked on {}:\n{stderr}",
+        input.display()
+    );
+    stderr
+}
+
+#[test]
+fn no_day_panics_on_malformed_input() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("malformed");
+    fs::create_dir_all(&dir).unwrap();
+    for day in 1..=25 {
+        let examples = examples(day);
+        assert!(!examples.is_empty(), "day {day} has no examples");
+        for example in examples {
+            let body = fs::read_to_string(&example).unwrap();
+            let stem = example.file_stem().unwrap().to_string_lossy().into_owned();
+            for (n, input) in mutations(&body).into_iter().enumerate() {
+                let path = dir.join(format!("{day:02}-{stem}-{n}.txt"));
+                fs::write(&path, input).unwrap();
+                for part in 1..=2 {
+                    run(day, part, &path);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_errors_point_at_the_input() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("bad-cube.txt");
+    fs::write(&path, "Game 1: 3 blue, 4 red\nGame 2: 1 purple\n").unwrap();
+    let stderr = run(2, 1, &path);
+    let expected = format!("{}:2:11: unknown color purple", path.display());
+    assert!(stderr.contains(&expected), "{stderr}");
+    assert!(stderr.contains("2 | Game 2: 1 purple\n  |           ^^^^^^"), "{stderr}");
+}
@@ -0,0 +1,40 @@
+//! Day options given with `--opt` or `AOC_OPTS`.
+
+use std::path::Path;
+use std::process::Command;
+
+fn run_day24(opts: &[&str], env: Option<&str>) -> std::process::Output {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.env_remove("AOC_OPTS");
+    if let Some(env) = env {
+        command.env("AOC_OPTS", env);
+    }
+    command.args(opts).args(["run", "--part", "1", "24"]).arg(root.join("24-hail/example.txt")).output().unwrap()
+}
+
+#[test]
+fn day24_test_area_comes_from_an_option() {
+    let output = run_day24(&["--opt", "area=7..=27"], None);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2\n");
+
+    let output = run_day24(&[], Some("area=7..=27"));
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2\n");
+
+    // The example's paths all cross far outside the puzzle's own area.
+    let output = run_day24(&[], None);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "0\n");
+}
+
+#[test]
+fn invalid_options_are_reported() {
+    let output = run_day24(&["--opt", "area"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected KEY=VALUE"));
+
+    let output = run_day24(&["--opt", "area=7..27"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid option area=7..27"));
+}
@@ -0,0 +1,58 @@
+//! `aoc new` lays out a day crate and lists it in the workspace.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const MANIFEST: &str = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"01-trebuchet\",\n    \"aoc\",\n    \"common\",\n]\n";
+
+fn aoc_new(root: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).arg("new").args(args).current_dir(root).output().unwrap()
+}
+
+#[test]
+fn new_day_is_scaffolded_and_added_to_the_workspace() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc")).unwrap();
+    fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+    // Run from a subdirectory, as from inside another crate.
+    let output = aoc_new(&root.join("aoc"), &["7", "hot_air"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let day = root.join("07-hot_air");
+    for file in ["Cargo.toml", "src/main.rs", "src/lib.rs", "src/generator.rs", "tests/example.rs", "example.txt"] {
+        assert!(day.join(file).is_file(), "missing {file}");
+    }
+    assert_eq!(fs::read_to_string(day.join("src/main.rs")).unwrap(), "common::main!(hot_air::HotAir);\n");
+    assert!(fs::read_to_string(day.join("tests/example.rs")).unwrap().contains("/example.txt"));
+    // `aoc/src/days.rs` lists every day's generator, so a new day needs one to register.
+    assert!(fs::read_to_string(day.join("src/lib.rs")).unwrap().starts_with("pub mod generator;\n"));
+    assert!(fs::read_to_string(day.join("src/generator.rs")).unwrap().contains("pub fn input<R: Rng + ?Sized>"));
+    assert!(fs::read_to_string(day.join("Cargo.toml")).unwrap().contains("rand = "));
+    assert_eq!(
+        fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+        MANIFEST.replace("\"01-trebuchet\",\n", "\"01-trebuchet\",\n    \"07-hot_air\",\n")
+    );
+
+    let again = aoc_new(&root, &["7", "hot_air"]);
+    assert!(!again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already exists"));
+}
+
+#[test]
+fn bad_days_and_names_are_rejected() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold-bad");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+    fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+    fs::create_dir(root.join("03-gears")).unwrap();
+    for args in [["26", "late"], ["0", "early"], ["3", "Gears"], ["3", "3d"], ["3", "other"]] {
+        let output = aoc_new(&root, &args);
+        assert!(!output.status.success(), "{args:?}");
+    }
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), MANIFEST);
+    assert_eq!(fs::read_dir(&root).unwrap().count(), 2);
+    let stderr = String::from_utf8_lossy(&aoc_new(&root, &["3", "other"]).stderr).into_owned();
+    assert!(stderr.contains("day 3 already exists in"), "{stderr}");
+}
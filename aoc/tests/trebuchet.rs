@@ -0,0 +1,111 @@
+//! Day 1's spelled out digits in other languages and custom tables.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn write(name: &str, body: &str) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, body).unwrap();
+    path
+}
+
+fn part2(words: &str, input: &Path) -> Output {
+    part2_with(&[&format!("words={words}")], input)
+}
+
+fn part2_with(opts: &[&str], input: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env_remove("AOC_OPTS")
+        .args(opts.iter().flat_map(|opt| ["--opt", opt]))
+        .args(["run", "--part", "2", "1"])
+        .arg(input)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn presets_read_other_languages() {
+    let input = write("trebuchet-languages.txt", "zweiundvierzig\nachtzehn3\nsiebeneinsneun\nxxfünfx\n");
+    let output = part2("de", &input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", 24 + 83 + 79 + 55));
+
+    let input = write("trebuchet-spanish.txt", "dosuno\nnueveocho7\n");
+    let output = part2("es", &input);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", 21 + 97));
+}
+
+#[test]
+fn tables_load_from_files() {
+    let table = write("trebuchet-roman.txt", "# Roman numerals, written out\n\nuno 1\nduo 2\ntres 3\n");
+    let input = write("trebuchet-roman-input.txt", "xduotresx\nuno\n");
+    let output = part2(table.to_str().unwrap(), &input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", 23 + 11));
+}
+
+#[test]
+fn extended_numbers_read_compounds_by_digit_or_value() {
+    let input = write("trebuchet-extended.txt", "twenty-one\neighteen7\nzeroeightwo\nsixty\nxsixty-threeseven\neightwo\n");
+    let output = part2("extended", &input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", 21 + 17 + 2 + 60 + 67 + 82));
+
+    let output = part2_with(&["words=extended", "compounds=value"], &input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", 2121 + 187 + 2 + 6060 + 637 + 82));
+
+    let output = part2_with(&["words=extended", "compounds=all"], &input);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid option compounds=all: expected digits or value"));
+}
+
+#[test]
+fn ambiguous_tables_are_rejected() {
+    let input = write("trebuchet-ambiguous-input.txt", "1\n");
+    let table = write("trebuchet-ambiguous.txt", "one 1\ntwo 2\nlone 7\n");
+    let stderr = String::from_utf8_lossy(&part2(table.to_str().unwrap(), &input).stderr).into_owned();
+    assert!(stderr.contains(":3:1: 'one' (1) is inside 'lone' (7)"), "{stderr}");
+
+    let table = write("trebuchet-twice.txt", "one 1\none 1\n");
+    let stderr = String::from_utf8_lossy(&part2(table.to_str().unwrap(), &input).stderr).into_owned();
+    assert!(stderr.contains(":2:1: 'one' is listed twice"), "{stderr}");
+
+    let table = write("trebuchet-digit.txt", "one 10\n");
+    let stderr = String::from_utf8_lossy(&part2(table.to_str().unwrap(), &input).stderr).into_owned();
+    assert!(stderr.contains(":1:5: 10 is not a single digit"), "{stderr}");
+
+    let stderr = String::from_utf8_lossy(&part2("xx", &input).stderr).into_owned();
+    assert!(stderr.contains("words=xx is neither a language (en, de, fr, es, extended) nor a readable file"), "{stderr}");
+}
+
+#[test]
+fn dump_explains_each_line() {
+    let input = write("trebuchet-explain.txt", "xeightwo3\nnothing\n7\n");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env_remove("AOC_OPTS")
+        .args(["run", "--dump", "--part", "2", "1"])
+        .arg(&input)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with(
+            "line 1\nxeightwo3\n ^^^^^  ^\ndigits  8..9 3 (first, last)\nwords   1..6 eight (first), 5..8 two, 8..9 3 (last)\nvalue   83\n"
+        ),
+        "{stderr}"
+    );
+    assert!(stderr.contains("line 2\nnothing\ndigits  none\nwords   none\nvalue   none, the line has no digits or number words\n"), "{stderr}");
+    assert!(stderr.contains("line 3\n7\n^\n"), "{stderr}");
+
+    let part1 = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env_remove("AOC_OPTS")
+        .args(["run", "--dump", "--part", "1", "1"])
+        .arg(&input)
+        .output()
+        .unwrap();
+    let stderr1 = String::from_utf8_lossy(&part1.stderr);
+    assert!(stderr1.contains("line 2\nnothing\ndigits  none\nvalue   none, the line has no digits\n"), "{stderr1}");
+    assert!(stderr.contains("trebuchet-explain.txt:2:1: number not found"), "{stderr}");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,48 @@
+//! Drawing grids goes to stderr and leaves the answers on stdout untouched.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn visualize_draws_to_stderr() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    for (day, dir, title) in [
+        (10, "10-pipemaze", "loop"),
+        (13, "13-mirrors", "reflection"),
+        (14, "14-rocks", "tilted north"),
+        (16, "16-lava", "energized"),
+        (17, "17-crucible", "route"),
+        (18, "18-lagoon", "trench"),
+    ] {
+        let input = root.join(dir).join("example.txt");
+        let plain = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", &day.to_string()]).arg(&input).output().unwrap();
+        let drawn = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", "--visualize", &day.to_string()])
+            .arg(&input)
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+        assert!(drawn.status.success(), "day {day}: {}", String::from_utf8_lossy(&drawn.stderr));
+        assert_eq!(plain.stdout, drawn.stdout, "day {day}");
+        let stderr = String::from_utf8_lossy(&drawn.stderr);
+        assert!(stderr.starts_with(title), "day {day}: {stderr}");
+        assert!(!stderr.contains('\x1b'), "day {day} used colors with NO_COLOR set");
+    }
+}
+
+#[test]
+fn dump_prints_layers_to_stderr() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let input = root.join("22-slabs/example.txt");
+    let plain = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "22"]).arg(&input).output().unwrap();
+    let dumped = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "--dump", "22"]).arg(&input).env("NO_COLOR", "1").output().unwrap();
+    assert!(dumped.status.success(), "{}", String::from_utf8_lossy(&dumped.stderr));
+    assert_eq!(plain.stdout, dumped.stdout);
+    let stderr = String::from_utf8_lossy(&dumped.stderr);
+    assert!(stderr.starts_with("z = 1\n.A.\n.A.\n.A.\n"), "{stderr}");
+    assert!(stderr.contains("z = 6\n...\n.G.\n...\n"), "{stderr}");
+
+    // The stack settles while parsing, so either part shows it.
+    let part2 = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "--dump", "--part", "2", "22"]).arg(&input).env("NO_COLOR", "1").output().unwrap();
+    assert!(String::from_utf8_lossy(&part2.stderr).starts_with("z = 1\n.A.\n"), "{}", String::from_utf8_lossy(&part2.stderr));
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use eyre::{eyre, Report, Result};
+
+/// A named input, used to point parse errors at the offending text.
+///
+/// Errors read `name:line:col: message` followed by the source line with the span
+/// underlined by carets.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    name: &'a str,
+    body: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(name: &'a str, body: &'a str) -> Self {
+        Source { name, body }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn body(&self) -> &'a str {
+        self.body
+    }
+
+    /// Builds an error underlining `span`, which should be a slice of the body.
+    ///
+    /// Spans from elsewhere are reported against the file name alone.
+    pub fn error(&self, span: &str, msg: impl Display) -> Report {
+        match self.offset(span) {
+            Some(offset) => {
+                let before = &self.body[..offset];
+                let line = before.matches('\n').count() + 1;
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                let col = before[line_start..].chars().count() + 1;
+                self.render(line, col, span.chars().count(), &msg)
+            }
+            None => eyre!("{}: {msg:#}", self.name),
+        }
+    }
+
+    /// Builds an error pointing at a 1-based line and column.
+    pub fn error_at(&self, line: usize, col: usize, msg: impl Display) -> Report {
+        self.render(line, col, 1, &msg)
+    }
+
+    /// Builds an error pointing just past the last character of the input.
+    pub fn eof(&self, msg: impl Display) -> Report {
+        self.error(&self.body[self.body.trim_end().len()..], msg)
+    }
+
+    /// Parses `span` with `FromStr`, reporting failures at its location.
+    pub fn parse<T>(&self, span: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        span.parse().map_err(|e| self.error(span, e))
+    }
+
+    fn offset(&self, span: &str) -> Option<usize> {
+        let start = (span.as_ptr() as usize).checked_sub(self.body.as_ptr() as usize)?;
+        (start + span.len() <= self.body.len()).then_some(start)
+    }
+
+    fn render(&self, line: usize, col: usize, len: usize, msg: &dyn Display) -> Report {
+        let text = self.body.lines().nth(line.saturating_sub(1)).unwrap_or("");
+        render(self.name, line, col, len, text, msg)
+    }
+}
+
+/// Builds an error at a 1-based line and column of `name` whose text is `text`, with `len`
+/// characters underlined, for inputs read a line at a time rather than held in a [`Source`].
+pub fn render(name: &str, line: usize, col: usize, len: usize, text: &str, msg: &dyn Display) -> Report {
+    let len = len.min(text.chars().count().saturating_sub(col.saturating_sub(1))).max(1);
+    let width = line.to_string().len();
+    eyre!(
+        "{name}:{line}:{col}: {msg:#}\n{:width$} |\n{line} | {text}\n{:width$} | {}{}",
+        "",
+        "",
+        " ".repeat(col.saturating_sub(1)),
+        "^".repeat(len)
+    )
+}
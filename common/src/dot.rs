@@ -0,0 +1,30 @@
+//! Writes graphs of days that support it to a Graphviz DOT file.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use eyre::{Result, WrapErr};
+
+static OUTPUT: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Sets the file days that support it write their graph to.
+pub fn set_output(path: Option<PathBuf>) {
+    *OUTPUT.lock().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+pub fn output() -> Option<PathBuf> {
+    OUTPUT.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Reads the DOT file from `AOC_DOT`.
+pub fn init_from_env() {
+    if let Some(path) = std::env::var_os("AOC_DOT") {
+        set_output(Some(path.into()));
+    }
+}
+
+/// Writes a graph already formatted as DOT, such as petgraph's `Dot`.
+pub fn write(path: &Path, graph: impl std::fmt::Display) -> Result<()> {
+    fs::write(path, graph.to_string()).wrap_err_with(|| format!("cannot write {}", path.display()))
+}
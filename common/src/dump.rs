@@ -0,0 +1,26 @@
+//! Dumps of a solver's intermediate state, for days whose state is more than one grid.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables dumps when `AOC_DUMP` is set in the environment.
+pub fn init_from_env() {
+    if std::env::var_os("AOC_DUMP").is_some() {
+        set_enabled(true);
+    }
+}
+
+/// Prints one section of a dump to stderr under a heading, keeping stdout for the answers.
+pub fn section(heading: &str, text: impl fmt::Display) {
+    eprintln!("{heading}\n{text}");
+}
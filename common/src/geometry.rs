@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use eyre::{eyre, Report, Result};
+
+/// A direction on a grid indexed `[row, col]`, where rows grow downwards.
+///
+/// Parses from both the letter (`U`, `D`, `L`, `R`) and compass (`N`, `S`, `W`, `E`) forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction in clockwise order, starting from `Up`.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Position of the direction in `ALL`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// The `[row, col]` change of a single step.
+    pub fn delta(self) -> [isize; 2] {
+        match self {
+            Direction::Up => [-1, 0],
+            Direction::Right => [0, 1],
+            Direction::Down => [1, 0],
+            Direction::Left => [0, -1],
+        }
+    }
+
+    /// Moves `pos` by `steps` without any bounds checking.
+    pub fn shift(self, pos: [isize; 2], steps: isize) -> [isize; 2] {
+        let [dr, dc] = self.delta();
+        [pos[0] + dr * steps, pos[1] + dc * steps]
+    }
+
+    /// Moves `pos` by `steps`, returning `None` if the result lies outside an array of shape `dim`.
+    pub fn offset<P: Position>(self, pos: P, steps: usize, dim: (usize, usize)) -> Option<P> {
+        pos.offset(self, steps, dim)
+    }
+}
+
+/// Grid positions that can be moved in a `Direction` while staying inside an array.
+pub trait Position: Copy {
+    fn offset(self, dir: Direction, steps: usize, dim: (usize, usize)) -> Option<Self>;
+}
+
+impl Position for [usize; 2] {
+    fn offset(self, dir: Direction, steps: usize, (rows, cols): (usize, usize)) -> Option<Self> {
+        let [row, col] = self;
+        let pos = match dir {
+            Direction::Up => [row.checked_sub(steps)?, col],
+            Direction::Right => [row, col.checked_add(steps)?],
+            Direction::Down => [row.checked_add(steps)?, col],
+            Direction::Left => [row, col.checked_sub(steps)?],
+        };
+        (pos[0] < rows && pos[1] < cols).then_some(pos)
+    }
+}
+
+impl Position for [isize; 2] {
+    fn offset(self, dir: Direction, steps: usize, (rows, cols): (usize, usize)) -> Option<Self> {
+        let pos = dir.shift(self, isize::try_from(steps).ok()?);
+        let inside = |x: isize, len: usize| usize::try_from(x).is_ok_and(|x| x < len);
+        (inside(pos[0], rows) && inside(pos[1], cols)).then_some(pos)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Report;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'U' | 'N' => Ok(Direction::Up),
+            'R' | 'E' => Ok(Direction::Right),
+            'D' | 'S' => Ok(Direction::Down),
+            'L' | 'W' => Ok(Direction::Left),
+            _ => Err(eyre!("invalid direction '{c}'")),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(eyre!("invalid direction '{s}'")),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+use std::iter::{Enumerate, Peekable};
+use std::str::Lines;
+
+use eyre::{eyre, Result};
+use ndarray::Array2;
+
+use crate::diagnostic::Source;
+
+/// Parser for rectangular character grids.
+///
+/// Iterating yields one `Array2` per block of non-blank lines, so files holding several
+/// grids separated by blank lines can be read in sequence.
+pub struct Grid<'a, T> {
+    source: Source<'a>,
+    lines: Peekable<Enumerate<Lines<'a>>>,
+    parse: fn(char) -> Result<T>,
+}
+
+impl<'a, T> Grid<'a, T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    pub fn new(name: &'a str, body: &'a str) -> Self {
+        Self::with_parser(name, body, |c| T::try_from(c).map_err(|e| eyre!("{e}")))
+    }
+}
+
+impl<'a, T> Grid<'a, T> {
+    pub fn with_parser(name: &'a str, body: &'a str, parse: fn(char) -> Result<T>) -> Self {
+        Grid {
+            source: Source::new(name, body),
+            lines: body.lines().enumerate().peekable(),
+            parse,
+        }
+    }
+
+    /// Parses a body that must contain exactly one grid.
+    pub fn single(mut self) -> Result<Array2<T>> {
+        let array = self.next().unwrap_or_else(|| Err(self.source.eof("no grid found")))?;
+        self.skip_blank();
+        match self.lines.next() {
+            Some((_, line)) => Err(self.source.error(line, "unexpected second grid")),
+            None => Ok(array),
+        }
+    }
+
+    fn skip_blank(&mut self) {
+        while self.lines.next_if(|(_, l)| l.is_empty()).is_some() {}
+    }
+
+    fn parse_block(&mut self) -> Result<Array2<T>> {
+        let mut rows = 0usize;
+        let mut columns = 0usize;
+        let mut values = Vec::new();
+        while let Some((lineno, line)) = self.lines.next_if(|(_, l)| !l.is_empty()) {
+            rows += 1;
+            let mut count = 0usize;
+            for c in line.chars() {
+                count += 1;
+                if rows > 1 && count > columns {
+                    break;
+                }
+                let value =
+                    (self.parse)(c).map_err(|e| self.source.error_at(lineno + 1, count, e))?;
+                values.push(value);
+            }
+            if rows == 1 {
+                columns = count;
+            } else if count != columns {
+                let count = line.chars().count();
+                let msg = format!("expected {columns} columns but got {count}");
+                return Err(self.source.error_at(lineno + 1, columns.min(count) + 1, msg));
+            }
+        }
+        Ok(Array2::from_shape_vec((rows, columns), values)?)
+    }
+}
+
+impl<T> Iterator for Grid<'_, T> {
+    type Item = Result<Array2<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_blank();
+        self.lines.peek()?;
+        Some(self.parse_block())
+    }
+}
+
+/// Parses a body holding a single grid of `T`.
+pub fn parse<T>(name: &str, body: &str) -> Result<Array2<T>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    Grid::new(name, body).single()
+}
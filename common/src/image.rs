@@ -0,0 +1,78 @@
+//! Writes grids to image files, as PNG or binary PPM depending on the extension.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use eyre::{bail, Result, WrapErr};
+use ndarray::ArrayView2;
+
+static OUTPUT: Mutex<Option<PathBuf>> = Mutex::new(None);
+static FRAMES: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Sets the file days that support images write their final grid to.
+pub fn set_output(path: Option<PathBuf>) {
+    *OUTPUT.lock().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+pub fn output() -> Option<PathBuf> {
+    OUTPUT.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Sets the directory days that support it write one image per step into.
+pub fn set_frames(dir: Option<PathBuf>) {
+    *FRAMES.lock().unwrap_or_else(|e| e.into_inner()) = dir;
+}
+
+pub fn frames() -> Option<PathBuf> {
+    FRAMES.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Reads the image file and frames directory from `AOC_IMAGE` and `AOC_FRAMES`.
+pub fn init_from_env() {
+    if let Some(path) = std::env::var_os("AOC_IMAGE") {
+        set_output(Some(path.into()));
+    }
+    if let Some(dir) = std::env::var_os("AOC_FRAMES") {
+        set_frames(Some(dir.into()));
+    }
+}
+
+/// Writes a grid with one square of `color` per cell, scaled up so small grids stay visible.
+pub fn write<T>(path: &Path, array: ArrayView2<T>, color: impl Fn(&T) -> [u8; 3]) -> Result<()> {
+    let png = match path.extension().and_then(|e| e.to_str()) {
+        Some("ppm") => false,
+        Some("png") => true,
+        _ => bail!("cannot write {}: use a .png or .ppm file", path.display()),
+    };
+    let (rows, cols) = array.dim();
+    let scale = (1024 / rows.max(cols).max(1)).clamp(1, 8);
+    let (width, height) = (cols * scale, rows * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in array.rows() {
+        let line: Vec<u8> = row.iter().flat_map(|v| color(v).repeat(scale)).collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+    let file = File::create(path).wrap_err_with(|| format!("cannot create {}", path.display()))?;
+    let mut out = BufWriter::new(file);
+    if png {
+        let mut encoder = png::Encoder::new(&mut out, u32::try_from(width)?, u32::try_from(height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&pixels)?;
+    } else {
+        write!(out, "P6\n{width} {height}\n255\n")?;
+        out.write_all(&pixels)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Writes the `index`th frame of a sequence as a PNG in `dir`, creating it if needed.
+pub fn write_frame<T>(dir: &Path, index: usize, array: ArrayView2<T>, color: impl Fn(&T) -> [u8; 3]) -> Result<()> {
+    fs::create_dir_all(dir).wrap_err_with(|| format!("cannot create {}", dir.display()))?;
+    write(&dir.join(format!("frame-{index:05}.png")), array, color)
+}
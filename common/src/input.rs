@@ -0,0 +1,80 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use eyre::{Result, WrapErr};
+use flate2::read::MultiGzDecoder;
+
+/// Name given to input read from standard input.
+pub const STDIN: &str = "<stdin>";
+
+/// A puzzle input read into memory, with the name used in error messages.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub body: String,
+}
+
+/// Opens an input file, or standard input for `-`, to be read a little at a time.
+///
+/// Returns the name used in error messages with the reader. Files ending in `.gz` are
+/// decompressed as they are read.
+pub fn open(path: &Path) -> Result<(String, Box<dyn BufRead>)> {
+    if path == Path::new("-") {
+        return Ok((STDIN.to_string(), Box::new(io::stdin().lock())));
+    }
+    let name = path.to_string_lossy().into_owned();
+    let file = File::open(path).wrap_err_with(|| format!("cannot read {name}"))?;
+    if path.extension().is_some_and(|e| e == "gz") {
+        return Ok((name, Box::new(BufReader::new(MultiGzDecoder::new(file)))));
+    }
+    Ok((name, Box::new(BufReader::new(file))))
+}
+
+/// Reads an input file, or standard input for `-`, into memory.
+pub fn read(path: &Path) -> Result<Input> {
+    let (name, reader) = open(path)?;
+    read_all(name, reader)
+}
+
+/// Reads the rest of an input opened with [`open`] into memory.
+pub fn read_all(name: String, reader: impl BufRead) -> Result<Input> {
+    let body = io::read_to_string(reader).wrap_err_with(|| {
+        if name == STDIN {
+            "cannot read standard input".to_string()
+        } else if name.ends_with(".gz") {
+            format!("cannot decompress {name}")
+        } else {
+            format!("cannot read {name}")
+        }
+    })?;
+    Ok(Input { name, body })
+}
+
+/// Reads each input in turn and hands it to `solve`.
+///
+/// With several inputs a failure is reported and the rest still run; the error returned
+/// at the end counts the failures. A single input returns its error unchanged.
+pub fn for_each<P: AsRef<Path>>(paths: &[P], mut solve: impl FnMut(&Input) -> Result<()>) -> Result<()> {
+    for_each_reader(paths, |name, reader| solve(&read_all(name.to_string(), reader)?))
+}
+
+/// Opens each input in turn and hands its name and reader to `solve`, for days that read
+/// their input a little at a time. Failures are handled as by [`for_each`].
+pub fn for_each_reader<P: AsRef<Path>>(paths: &[P], mut solve: impl FnMut(&str, Box<dyn BufRead>) -> Result<()>) -> Result<()> {
+    if let [path] = paths {
+        let (name, reader) = open(path.as_ref())?;
+        return solve(&name, reader);
+    }
+    let mut failed = 0;
+    for path in paths {
+        if let Err(e) = open(path.as_ref()).and_then(|(name, reader)| solve(&name, reader)) {
+            failed += 1;
+            eprintln!("Error: {e:?}");
+        }
+    }
+    if failed > 0 {
+        eyre::bail!("{failed} of {} inputs failed", paths.len());
+    }
+    Ok(())
+}
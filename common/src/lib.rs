@@ -0,0 +1,15 @@
+pub mod diagnostic;
+pub mod dot;
+pub mod dump;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod options;
+pub mod random;
+pub mod render;
+pub mod sequence;
+pub mod solution;
+pub mod trace;
+
+pub use eyre;
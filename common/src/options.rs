@@ -0,0 +1,52 @@
+//! Puzzle settings that are not part of the input, such as day 24's test area.
+//!
+//! They are given as `KEY=VALUE` on the command line with `--opt`, or space separated in
+//! `AOC_OPTS`, and each day documents the keys it reads.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use eyre::{eyre, Result};
+
+static OPTIONS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+pub fn set(key: &str, value: &str) {
+    OPTIONS.lock().unwrap_or_else(|e| e.into_inner()).insert(key.to_string(), value.to_string());
+}
+
+pub fn remove(key: &str) -> Option<String> {
+    OPTIONS.lock().unwrap_or_else(|e| e.into_inner()).remove(key)
+}
+
+pub fn get(key: &str) -> Option<String> {
+    OPTIONS.lock().unwrap_or_else(|e| e.into_inner()).get(key).cloned()
+}
+
+/// Parses the option `key` if it is set.
+pub fn parse<T>(key: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    get(key).map(|v| v.parse().map_err(|e| eyre!("invalid option {key}={v}: {e}"))).transpose()
+}
+
+/// Parses a `KEY=VALUE` command line argument.
+pub fn parse_setting(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, not '{s}'")),
+    }
+}
+
+/// Sets the options listed in `AOC_OPTS`.
+pub fn init_from_env() -> Result<()> {
+    if let Ok(settings) = std::env::var("AOC_OPTS") {
+        for setting in settings.split_whitespace() {
+            let (key, value) = parse_setting(setting).map_err(|e| eyre!("AOC_OPTS: {e}"))?;
+            set(&key, &value);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Building blocks for the random input generators.
+
+use std::collections::HashMap;
+
+use ndarray::Array2;
+use rand::Rng;
+
+use crate::geometry::Direction;
+
+/// Neighbours of a cell in clockwise order, starting from the one above.
+const RING: [[isize; 2]; 8] = [[-1, 0], [-1, 1], [0, 1], [1, 1], [1, 0], [1, -1], [0, -1], [-1, -1]];
+
+fn occupied(region: &Array2<bool>, [row, col]: [usize; 2], [dr, dc]: [isize; 2]) -> bool {
+    let (rows, cols) = region.dim();
+    match (row.checked_add_signed(dr), col.checked_add_signed(dc)) {
+        (Some(r), Some(c)) if r < rows && c < cols => region[[r, c]],
+        _ => false,
+    }
+}
+
+/// Whether adding `cell` keeps the region free of holes and of cells touching only at a corner.
+///
+/// That holds when the occupied neighbours form one unbroken run around the cell.
+fn is_simple(region: &Array2<bool>, cell: [usize; 2]) -> bool {
+    let ring = RING.map(|d| occupied(region, cell, d));
+    let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+    runs == 1 && [0, 2, 4, 6].iter().any(|&i| ring[i])
+}
+
+/// Grows a random region of about half the cells, whose outline is a single simple loop.
+pub fn region<R: Rng + ?Sized>(rng: &mut R, rows: usize, cols: usize) -> Array2<bool> {
+    let mut region = Array2::from_elem((rows, cols), false);
+    let mut size = 0;
+    let mut frontier = vec![[rng.gen_range(0..rows), rng.gen_range(0..cols)]];
+    while size < (rows * cols).div_ceil(2) && !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if region[cell] || (size > 0 && !is_simple(&region, cell)) {
+            continue;
+        }
+        region[cell] = true;
+        size += 1;
+        for dir in Direction::ALL {
+            if let Some(next) = dir.offset(cell, 1, (rows, cols)) {
+                if !region[next] {
+                    frontier.push(next);
+                }
+            }
+        }
+    }
+    region
+}
+
+/// Traces the outline of a region along the cell corners, clockwise.
+///
+/// Returns the top-left corner of the first cell, which the loop starts from, and the
+/// unit moves around the loop. Corner `[r, c]` is the top-left corner of cell `[r, c]`.
+pub fn outline(region: &Array2<bool>) -> ([usize; 2], Vec<Direction>) {
+    let (rows, cols) = region.dim();
+    let mut next = HashMap::new();
+    for ((r, c), &inside) in region.indexed_iter() {
+        if !inside {
+            continue;
+        }
+        let cell = [r, c];
+        if !occupied(region, cell, [-1, 0]) {
+            next.insert([r, c], Direction::Right);
+        }
+        if !occupied(region, cell, [0, 1]) {
+            next.insert([r, c + 1], Direction::Down);
+        }
+        if !occupied(region, cell, [1, 0]) {
+            next.insert([r + 1, c + 1], Direction::Left);
+        }
+        if !occupied(region, cell, [0, -1]) {
+            next.insert([r + 1, c], Direction::Up);
+        }
+    }
+    let start = region
+        .indexed_iter()
+        .find_map(|((r, c), &inside)| inside.then_some([r, c]))
+        .unwrap_or([0, 0]);
+    let mut moves = Vec::new();
+    let mut corner = start;
+    while let Some(&dir) = next.get(&corner) {
+        moves.push(dir);
+        corner = dir.offset(corner, 1, (rows + 1, cols + 1)).unwrap_or(start);
+        if corner == start {
+            break;
+        }
+    }
+    (start, moves)
+}
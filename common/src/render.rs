@@ -0,0 +1,181 @@
+//! Draws grids to the terminal, for following what a solver does.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ndarray::ArrayView2;
+
+use crate::geometry::Direction;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables drawing when `AOC_VISUALIZE` is set in the environment.
+pub fn init_from_env() {
+    if std::env::var_os("AOC_VISUALIZE").is_some() {
+        set_enabled(true);
+    }
+}
+
+/// Prints a drawing to stderr under a title, keeping stdout for the answers.
+pub fn show(title: &str, drawing: impl fmt::Display) {
+    eprintln!("{title}\n{drawing}");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// The ANSI SGR parameters for this color, offset by 30 for the foreground or 40 for
+    /// the background.
+    fn sgr(self, base: u8) -> String {
+        match self {
+            Color::Black => format!("{base}"),
+            Color::Red => format!("{}", base + 1),
+            Color::Green => format!("{}", base + 2),
+            Color::Yellow => format!("{}", base + 3),
+            Color::Blue => format!("{}", base + 4),
+            Color::Magenta => format!("{}", base + 5),
+            Color::Cyan => format!("{}", base + 6),
+            Color::White => format!("{}", base + 7),
+            Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        }
+    }
+}
+
+/// How a single cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub glyph: char,
+    pub color: Option<Color>,
+}
+
+impl Style {
+    pub fn plain(glyph: char) -> Self {
+        Style { glyph, color: None }
+    }
+
+    pub fn colored(glyph: char, color: Color) -> Self {
+        Style { glyph, color: Some(color) }
+    }
+}
+
+/// Cells drawn over the grid on a colored background, optionally with their own glyph.
+struct Layer {
+    cells: HashMap<[usize; 2], Option<char>>,
+    color: Color,
+}
+
+/// A grid ready to be drawn, with overlays applied in the order they were added.
+///
+/// Colors use ANSI escapes, and are left out when stderr is not a terminal or `NO_COLOR`
+/// is set.
+pub struct Render<'a, T> {
+    array: ArrayView2<'a, T>,
+    style: Box<dyn Fn(&T) -> Style + 'a>,
+    layers: Vec<Layer>,
+    color: bool,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub fn new(array: ArrayView2<'a, T>, style: impl Fn(&T) -> Style + 'a) -> Self {
+        Render {
+            array,
+            style: Box::new(style),
+            layers: Vec::new(),
+            color: std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    /// Forces colors on or off.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Highlights cells, keeping their glyphs.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = [usize; 2]>, color: Color) -> Self {
+        let cells = cells.into_iter().map(|c| (c, None)).collect();
+        self.layers.push(Layer { cells, color });
+        self
+    }
+
+    /// Highlights cells and draws them with `glyph`, which also shows without colors.
+    pub fn mark(mut self, cells: impl IntoIterator<Item = [usize; 2]>, color: Color, glyph: char) -> Self {
+        let cells = cells.into_iter().map(|c| (c, Some(glyph))).collect();
+        self.layers.push(Layer { cells, color });
+        self
+    }
+
+    /// Highlights a path of cells, drawing an arrow on each cell towards the next.
+    pub fn path(mut self, cells: impl IntoIterator<Item = [usize; 2]>, color: Color) -> Self {
+        let cells: Vec<_> = cells.into_iter().collect();
+        let arrows = cells.iter().enumerate().map(|(i, &cell)| {
+            let arrow = cells.get(i + 1).and_then(|&next| {
+                Direction::ALL.into_iter().find(|d| d.offset(cell, 1, self.array.dim()) == Some(next))
+            });
+            let glyph = arrow.map(|d| match d {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            });
+            (cell, glyph)
+        });
+        let cells = arrows.collect();
+        self.layers.push(Layer { cells, color });
+        self
+    }
+}
+
+impl<T> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.array.rows().into_iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                let mut style = (self.style)(value);
+                let mut background = None;
+                for layer in self.layers.iter() {
+                    if let Some(glyph) = layer.cells.get(&[r, c]) {
+                        style.glyph = glyph.unwrap_or(style.glyph);
+                        background = Some(layer.color);
+                    }
+                }
+                let mut codes = Vec::new();
+                if self.color {
+                    if let Some(color) = background {
+                        codes.push(color.sgr(40));
+                        codes.push(Color::Black.sgr(30));
+                    } else if let Some(color) = style.color {
+                        codes.push(color.sgr(30));
+                    }
+                }
+                if codes.is_empty() {
+                    write!(f, "{}", style.glyph)?;
+                } else {
+                    write!(f, "\x1b[{}m{}\x1b[0m", codes.join(";"), style.glyph)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
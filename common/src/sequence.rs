@@ -0,0 +1,35 @@
+//! Extrapolating integer sequences with finite differences.
+
+/// The first entry of each row of differences, down to the first constant row.
+fn leading_differences(seq: &[i64]) -> Vec<i64> {
+    let mut leading = Vec::new();
+    let mut row = seq.to_vec();
+    while let Some(&first) = row.first() {
+        leading.push(first);
+        if row.iter().all(|&d| d == first) {
+            break;
+        }
+        row = row.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+    leading
+}
+
+/// Value at `index` of the lowest degree polynomial through the sequence.
+///
+/// Index 0 is the first element and negative indices extrapolate backwards. Uses Newton's
+/// forward difference formula, so far away indices cost no more than near ones.
+pub fn value_at(seq: &[i64], index: i64) -> i64 {
+    // binomial = C(index, k), which stays an integer after each step.
+    let mut binomial = 1i128;
+    let mut value = 0i128;
+    for (k, d) in leading_differences(seq).into_iter().enumerate() {
+        value += binomial * i128::from(d);
+        binomial = binomial * (i128::from(index) - k as i128) / (k as i128 + 1);
+    }
+    value as i64
+}
+
+/// The values just before and just after the sequence.
+pub fn extrapolate(seq: &[i64]) -> (i64, i64) {
+    (value_at(seq, -1), value_at(seq, seq.len() as i64))
+}
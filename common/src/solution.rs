@@ -0,0 +1,203 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+use eyre::{eyre, Result};
+
+/// A puzzle answer. Days answer with `u64`, or `i64` where the result can be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// A day's puzzle: the input is parsed once and then solved for each part.
+pub trait Solution<'a>: Sized {
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(fname: &'a str, body: &'a str) -> Result<Self>;
+    fn part1(&self) -> Result<Self::Answer1>;
+    fn part2(&self) -> Result<Self::Answer2>;
+
+    /// Writes files that do not depend on the part, such as a `--dot` graph. Runs once per
+    /// input before the parts, and not at all when timing.
+    fn export(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Object safe view of a parsed `Solution`, so days can be dispatched at runtime.
+pub trait DynSolution {
+    fn part1(&self) -> Result<Answer>;
+    fn part2(&self) -> Result<Answer>;
+    fn export(&self) -> Result<()>;
+
+    /// Solves the given part, which must be 1 or 2.
+    fn part(&self, part: u8) -> Result<Answer> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => Err(eyre!("no part {part}")),
+        }
+    }
+}
+
+struct Erased<'a, S: Solution<'a>> {
+    solution: S,
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a, S: Solution<'a>> DynSolution for Erased<'a, S> {
+    fn part1(&self) -> Result<Answer> {
+        self.solution.part1().map(Into::into)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.solution.part2().map(Into::into)
+    }
+
+    fn export(&self) -> Result<()> {
+        self.solution.export()
+    }
+}
+
+pub type ParseFn = for<'a> fn(&'a str, &'a str) -> Result<Box<dyn DynSolution + 'a>>;
+
+pub fn parse<'a, S: Solution<'a> + 'a>(
+    fname: &'a str,
+    body: &'a str,
+) -> Result<Box<dyn DynSolution + 'a>> {
+    let solution = S::parse(fname, body)?;
+    Ok(Box::new(Erased {
+        solution,
+        marker: PhantomData,
+    }))
+}
+
+/// Solves the given parts of a day in one pass over a reader, a line at a time, for days
+/// whose inputs may be too large to hold in memory.
+///
+/// The outer error is for input that cannot be read, and each part has its own answer or
+/// error, in the order asked for.
+pub type StreamFn = fn(&str, Box<dyn BufRead>, &[u8]) -> Result<Vec<Result<Answer>>>;
+
+/// Body of the per-day binaries: solves both parts for each input named on the command line.
+///
+/// `-` reads standard input and `.gz` files are decompressed. With several inputs each
+/// file's answers follow a `==> name <==` header. See [`args`] for the other flags.
+pub fn run_main(parse: ParseFn) -> Result<()> {
+    let paths = args()?;
+    crate::input::for_each(&paths, |input| {
+        if paths.len() > 1 {
+            println!("==> {} <==", input.name);
+        }
+        let solution = parse(&input.name, &input.body)?;
+        solution.export()?;
+        println!("{}", solution.part1()?);
+        println!("{}", solution.part2()?);
+        Ok(())
+    })
+}
+
+/// Like [`run_main`], for a day that streams its inputs rather than reading them into memory.
+pub fn run_stream(stream: StreamFn) -> Result<()> {
+    let paths = args()?;
+    crate::input::for_each_reader(&paths, |name, reader| {
+        if paths.len() > 1 {
+            println!("==> {name} <==");
+        }
+        for answer in stream(name, reader, &[1, 2])? {
+            println!("{}", answer?);
+        }
+        Ok(())
+    })
+}
+
+/// Applies the settings in the environment and the flags on the command line, and returns
+/// the input paths.
+///
+/// `--visualize` draws grids to stderr and `--dump` prints other intermediate state there.
+/// `--image FILE` saves grids as PNG or PPM, `--frames DIR` saves one PNG per step and
+/// `--dot FILE` saves graphs for Graphviz. `--opt KEY=VALUE`, which may be repeated, sets
+/// an option of the day.
+fn args() -> Result<Vec<OsString>> {
+    crate::trace::init_from_env();
+    crate::render::init_from_env();
+    crate::dump::init_from_env();
+    crate::image::init_from_env();
+    crate::dot::init_from_env();
+    crate::options::init_from_env()?;
+    let mut paths: Vec<_> = std::env::args_os().skip(1).collect();
+    if let Some(i) = paths.iter().position(|a| a == "--visualize") {
+        paths.remove(i);
+        crate::render::set_enabled(true);
+    }
+    if let Some(i) = paths.iter().position(|a| a == "--dump") {
+        paths.remove(i);
+        crate::dump::set_enabled(true);
+    }
+    if let Some(path) = take_value(&mut paths, "--image")? {
+        crate::image::set_output(Some(path.into()));
+    }
+    if let Some(dir) = take_value(&mut paths, "--frames")? {
+        crate::image::set_frames(Some(dir.into()));
+    }
+    if let Some(path) = take_value(&mut paths, "--dot")? {
+        crate::dot::set_output(Some(path.into()));
+    }
+    while let Some(setting) = take_value(&mut paths, "--opt")? {
+        let (key, value) = crate::options::parse_setting(&setting.to_string_lossy()).map_err(|e| eyre!(e))?;
+        crate::options::set(&key, &value);
+    }
+    if paths.is_empty() {
+        return Err(eyre!("filename was not provided"));
+    }
+    Ok(paths)
+}
+
+/// Removes `flag` and the value after it from the arguments.
+fn take_value(args: &mut Vec<OsString>, flag: &str) -> Result<Option<OsString>> {
+    let Some(i) = args.iter().position(|a| a == flag) else {
+        return Ok(None);
+    };
+    if i + 1 == args.len() {
+        return Err(eyre!("{flag} needs a value"));
+    }
+    args.remove(i);
+    Ok(Some(args.remove(i)))
+}
+
+/// Defines `main` for a day's binary from its `Solution` type.
+#[macro_export]
+macro_rules! main {
+    ($solution:ty) => {
+        fn main() -> $crate::eyre::Result<()> {
+            $crate::solution::run_main(|fname, body| {
+                $crate::solution::parse::<$solution>(fname, body)
+            })
+        }
+    };
+}
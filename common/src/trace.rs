@@ -0,0 +1,28 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables tracing when `AOC_TRACE` is set in the environment.
+pub fn init_from_env() {
+    if std::env::var_os("AOC_TRACE").is_some() {
+        set_enabled(true);
+    }
+}
+
+/// Prints diagnostics to stderr, but only when tracing has been enabled.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}